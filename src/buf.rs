@@ -0,0 +1,339 @@
+//! [StrBuf] stack string builder, [BufWriter] for writing to borrowed buffers,
+//! and [RingWriter] for writing to circular buffers
+//! 
+//! ```
+//! # use emstr::{StrBuf, BufWriter, Error};
+//! let mut s = StrBuf::<16>::new();
+//! 
+//! s.push_str("a=")?;
+//! s.push_fmt(&1u8)?;
+//! s.push(';')?;
+//! 
+//! assert_eq!(s.as_str(), "a=1;");
+//! 
+//! let mut buff = [0u8; 16];
+//! let mut w = BufWriter::new(&mut buff);
+//! 
+//! for v in [1u8, 2, 3] {
+//!     w.write(&v)?;
+//! }
+//! 
+//! assert_eq!(w.into_str()?, "123");
+//! # Ok::<(), Error>(())
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Scratch length for [RingWriter] values wrapping the end of the buffer
+const RING_SCRATCH_LEN: usize = 64;
+
+/// Fixed capacity string builder backed by a `[u8; N]` array, for incrementally
+/// building strings across control flow.
+/// 
+/// Pushes are atomic, on failure the existing content is left intact.
+#[derive(Clone, PartialEq, Debug)]
+pub struct StrBuf<const N: usize> {
+    buff: [u8; N],
+    len: usize,
+}
+
+impl <const N: usize> StrBuf<N> {
+    /// Create a new empty string buffer
+    pub const fn new() -> Self {
+        Self{
+            buff: [0u8; N],
+            len: 0,
+        }
+    }
+
+    /// Append a string slice
+    pub fn push_str(&mut self, s: &str) -> Result<(), Error> {
+        self.push_fmt(&s)
+    }
+
+    /// Append a character
+    pub fn push(&mut self, c: char) -> Result<(), Error> {
+        self.push_fmt(&c)
+    }
+
+    /// Append an [EncodeStr] value
+    pub fn push_fmt(&mut self, v: &impl EncodeStr) -> Result<(), Error> {
+        // Check remaining capacity
+        if self.len + v.len() > N {
+            return Err(Error::BufferLength)
+        }
+
+        // Encode value and ensure the content remains valid
+        let n = v.write(&mut self.buff[self.len..])?;
+        crate::debug_assert_len(v, n);
+        core::str::from_utf8(&self.buff[self.len..][..n]).map_err(|_| Error::InvalidUtf8)?;
+
+        self.len += n;
+
+        Ok(())
+    }
+
+    /// Fetch the current content as a string slice
+    pub fn as_str(&self) -> &str {
+        // Content is validated on push
+        core::str::from_utf8(&self.buff[..self.len]).unwrap()
+    }
+
+    /// Fetch the current content length
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether the buffer is empty
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Clear the buffer content
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl <const N: usize> Default for StrBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writer over a borrowed buffer, tracking the write offset across calls
+/// to avoid manual slicing when chaining encoders
+pub struct BufWriter<'a> {
+    buff: &'a mut [u8],
+    n: usize,
+}
+
+impl <'a> BufWriter<'a> {
+    /// Create a new writer over the provided buffer
+    pub fn new(buff: &'a mut [u8]) -> Self {
+        Self{
+            buff,
+            n: 0,
+        }
+    }
+
+    /// Create a new writer over the provided buffer starting at offset `start`,
+    /// for appending to existing content (such as a pre-filled header).
+    /// 
+    /// Returns [Error::BufferLength] where `start` exceeds the buffer length.
+    pub fn at(buff: &'a mut [u8], start: usize) -> Result<Self, Error> {
+        if start > buff.len() {
+            return Err(Error::BufferLength)
+        }
+
+        Ok(Self{
+            buff,
+            n: start,
+        })
+    }
+
+    /// Write an [EncodeStr] value at the current offset, returning the number
+    /// of bytes written and advancing the offset
+    pub fn write(&mut self, v: &(impl EncodeStr + ?Sized)) -> Result<usize, Error> {
+        let n = v.write(&mut self.buff[self.n..])?;
+        crate::debug_assert_len(v, n);
+        self.n += n;
+
+        Ok(n)
+    }
+
+    /// Fetch the number of bytes written, including any starting offset
+    pub const fn written(&self) -> usize {
+        self.n
+    }
+
+    /// Consume the writer, returning the written region as a string slice
+    pub fn into_str(self) -> Result<&'a str, Error> {
+        core::str::from_utf8(&self.buff[..self.n]).map_err(|_| Error::InvalidUtf8)
+    }
+}
+
+/// Writer for encoding values into a circular buffer, wrapping around the
+/// end of the buffer and overwriting the oldest data.
+/// 
+/// Values are encoded in place where they fit before the end of the buffer,
+/// values crossing the end are encoded to a 64 byte scratch buffer and copied
+/// either side of the wrap (so are limited to 64 bytes).
+pub struct RingWriter<'a> {
+    buff: &'a mut [u8],
+    head: usize,
+    wrapped: bool,
+}
+
+impl <'a> RingWriter<'a> {
+    /// Create a new ring writer over the provided buffer
+    pub fn new(buff: &'a mut [u8]) -> Self {
+        Self{
+            buff,
+            head: 0,
+            wrapped: false,
+        }
+    }
+
+    /// Write an [EncodeStr] value at the head, wrapping around the end of the
+    /// buffer, returning the number of bytes written
+    pub fn write(&mut self, v: &(impl EncodeStr + ?Sized)) -> Result<usize, Error> {
+        let l = v.len();
+        let space = self.buff.len() - self.head;
+
+        // Check value fits in the ring
+        if l > self.buff.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let n = match l <= space {
+            // Write in place
+            true => v.write(&mut self.buff[self.head..])?,
+            // Encode to scratch and copy either side of the wrap
+            false => {
+                let mut scratch = [0u8; RING_SCRATCH_LEN];
+                let n = v.write(&mut scratch)?;
+
+                self.buff[self.head..].copy_from_slice(&scratch[..space]);
+                self.buff[..n - space].copy_from_slice(&scratch[space..n]);
+
+                n
+            },
+        };
+        crate::debug_assert_len(v, n);
+
+        // Advance head
+        self.head += n;
+        if self.head >= self.buff.len() {
+            self.head -= self.buff.len();
+            self.wrapped = true;
+        }
+
+        Ok(n)
+    }
+
+    /// Fetch the current head (next write) index
+    pub const fn head(&self) -> usize {
+        self.head
+    }
+
+    /// Fetch the buffer contents in order as (older, newer) slices
+    pub fn as_slices(&self) -> (&[u8], &[u8]) {
+        match self.wrapped {
+            true => (&self.buff[self.head..], &self.buff[..self.head]),
+            false => (&self.buff[..self.head], &[]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error, helpers::Hex};
+    use super::{StrBuf, BufWriter, RingWriter};
+
+    #[test]
+    fn str_buf_push() {
+        let mut s = StrBuf::<16>::new();
+
+        for (k, v) in [("a", 1u8), ("b", 2u8)] {
+            if !s.is_empty() {
+                s.push(';').unwrap();
+            }
+            s.push_str(k).unwrap();
+            s.push('=').unwrap();
+            s.push_fmt(&v).unwrap();
+        }
+
+        assert_eq!(s.as_str(), "a=1;b=2");
+        assert_eq!(s.len(), 7);
+
+        s.clear();
+        assert_eq!(s.as_str(), "");
+    }
+
+    #[test]
+    fn str_buf_overflow() {
+        let mut s = StrBuf::<8>::new();
+
+        s.push_str("a=1;").unwrap();
+        assert_eq!(s.push_str("b=22222"), Err(Error::BufferLength));
+        assert_eq!(s.push_fmt(&123456u32), Err(Error::BufferLength));
+        assert_eq!(s.as_str(), "a=1;");
+
+        s.push_fmt(&1234u32).unwrap();
+        assert_eq!(s.as_str(), "a=1;1234");
+    }
+
+    #[test]
+    fn buf_writer_loop() {
+        let items: [&dyn EncodeStr; 5] = [&"id", &':', &12u8, &' ', &Hex([0xab, 0xcd])];
+
+        let mut a = [0u8; 32];
+        let mut w = BufWriter::new(&mut a);
+        for i in items {
+            w.write(i).unwrap();
+        }
+        assert_eq!(w.written(), 10);
+        let a = w.into_str().unwrap();
+
+        let mut b = [0u8; 32];
+        let n = crate::write!(b, "id", ':', 12u8, ' ', Hex([0xab, 0xcd])).unwrap();
+
+        assert_eq!(a.as_bytes(), &b[..n]);
+    }
+
+    #[test]
+    fn buf_writer_overflow() {
+        let mut buff = [0u8; 4];
+        let mut w = BufWriter::new(&mut buff);
+
+        assert_eq!(w.write(&"abc"), Ok(3));
+        assert_eq!(w.write(&"de"), Err(Error::BufferLength));
+        assert_eq!(w.into_str(), Ok("abc"));
+    }
+
+    #[test]
+    fn buf_writer_at() {
+        let mut buff = [0u8; 16];
+        buff[..3].copy_from_slice(b"hdr");
+
+        let mut w = BufWriter::at(&mut buff, 3).unwrap();
+        assert_eq!(w.write(&"-body"), Ok(5));
+        assert_eq!(w.written(), 8);
+        assert_eq!(w.into_str(), Ok("hdr-body"));
+
+        assert!(BufWriter::at(&mut buff, 16).is_ok());
+        assert!(matches!(BufWriter::at(&mut buff, 17), Err(Error::BufferLength)));
+    }
+
+    #[test]
+    fn ring_writer_wrap() {
+        let mut buff = [b'.'; 8];
+        let mut r = RingWriter::new(&mut buff);
+
+        assert_eq!(r.write(&"abcde"), Ok(5));
+        assert_eq!(r.head(), 5);
+        assert_eq!(r.as_slices(), (&b"abcde"[..], &b""[..]));
+
+        // Write across the end of the buffer
+        assert_eq!(r.write(&12345u32), Ok(5));
+        assert_eq!(r.head(), 2);
+        assert_eq!(r.as_slices(), (&b"cde123"[..], &b"45"[..]));
+
+        // Write exactly to the end
+        assert_eq!(r.write(&"xyzuvw"), Ok(6));
+        assert_eq!(r.head(), 0);
+        assert_eq!(r.as_slices(), (&b"45xyzuvw"[..], &b""[..]));
+
+        assert_eq!(&buff, b"45xyzuvw");
+    }
+
+    #[test]
+    fn ring_writer_overflow() {
+        let mut buff = [0u8; 4];
+        let mut r = RingWriter::new(&mut buff);
+
+        assert_eq!(r.write(&"abcde"), Err(Error::BufferLength));
+        assert_eq!(r.head(), 0);
+    }
+}
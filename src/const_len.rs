@@ -0,0 +1,211 @@
+//! `const fn` length helpers for sizing buffers at compile time
+//! 
+//! ```
+//! use emstr::{EncodeStr, const_len::{uint_len, str_len}};
+//! 
+//! const LABEL: &str = "count: ";
+//! const N: usize = str_len(LABEL) + uint_len(12345);
+//! 
+//! let mut buff = [0u8; N];
+//! let n = emstr::write!(buff, LABEL, 12345u32).unwrap();
+//! 
+//! assert_eq!(n, N);
+//! ```
+
+/// Fetch the encoded length of a string
+pub const fn str_len(s: &str) -> usize {
+    s.len()
+}
+
+/// Fetch the encoded length of an unsigned integer
+pub const fn uint_len(v: u128) -> usize {
+    let mut v = v;
+    let mut n = 1;
+
+    // Compute required characters
+    while v >= 10 {
+        v /= 10;
+        n += 1;
+    }
+
+    n
+}
+
+/// Fetch the encoded length of a signed integer, including any -ve sign
+pub const fn int_len(v: i128) -> usize {
+    match v < 0 {
+        true => 1 + uint_len(v.unsigned_abs()),
+        false => uint_len(v as u128),
+    }
+}
+
+/// Worst-case encoded length of a type, used by [buf!](crate::buf) and
+/// [max_len!](crate::max_len) to size buffers at compile time
+pub trait MaxLen {
+    /// Maximum number of bytes required to encode any value of the type
+    const MAX_LEN: usize;
+}
+
+/// Helper macro for implementing [MaxLen] for unsigned integers
+macro_rules! impl_uint_max_len {
+    ($($t:ty),+) => {
+        $(
+            impl MaxLen for $t {
+                const MAX_LEN: usize = uint_len(<$t>::MAX as u128);
+            }
+        )+
+    };
+}
+
+/// Helper macro for implementing [MaxLen] for signed integers
+macro_rules! impl_sint_max_len {
+    ($($t:ty),+) => {
+        $(
+            impl MaxLen for $t {
+                const MAX_LEN: usize = int_len(<$t>::MIN as i128);
+            }
+        )+
+    };
+}
+
+impl_uint_max_len!(u8, u16, u32, u64, u128, usize);
+impl_sint_max_len!(i8, i16, i32, i64, i128, isize);
+
+/// [MaxLen] for [char]s, encoded as up to 4 bytes of UTF-8
+impl MaxLen for char {
+    const MAX_LEN: usize = 4;
+}
+
+/// [MaxLen] for the unit type
+impl MaxLen for () {
+    const MAX_LEN: usize = 0;
+}
+
+/// Literal wrapper for computing the length of `&str` and `char` literals
+/// in `const` contexts, used by [max_len!](crate::max_len)
+#[doc(hidden)]
+pub struct Lit<T>(pub T);
+
+impl Lit<&str> {
+    /// Fetch the encoded length of a string literal
+    pub const fn encoded_len(self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Lit<char> {
+    /// Fetch the encoded length of a character literal
+    pub const fn encoded_len(self) -> usize {
+        self.0.len_utf8()
+    }
+}
+
+/// Helper macro for computing the worst-case encoded length of a list of
+/// types (via [MaxLen]) and `&str` / `char` literals, as a `const` expression
+/// 
+/// ```
+/// use emstr::max_len;
+/// 
+/// const N: usize = max_len!(u32, ' ', "/", u8);
+/// assert_eq!(N, 10 + 1 + 1 + 3);
+/// ```
+#[macro_export]
+macro_rules! max_len {
+    (@ $l:literal $(, $($r:tt)*)?) => {
+        $crate::const_len::Lit($l).encoded_len() $(+ $crate::max_len!(@ $($r)*))?
+    };
+    (@ $t:ty $(, $($r:tt)*)?) => {
+        <$t as $crate::const_len::MaxLen>::MAX_LEN $(+ $crate::max_len!(@ $($r)*))?
+    };
+    (@) => {
+        0
+    };
+    ($($r:tt)+) => {
+        $crate::max_len!(@ $($r)+)
+    };
+}
+
+/// Helper macro for creating a zeroed `[u8; N]` buffer sized to the
+/// worst-case encoded length of a list of types and literals (see [max_len!]),
+/// guaranteeing [write!](crate::write) cannot fail with
+/// [Error::BufferLength](crate::Error::BufferLength) for values of those types
+/// 
+/// ```
+/// use emstr::buf;
+/// 
+/// let mut buff = buf!(u32, ' ', "/", u8);
+/// let n = emstr::write!(buff, u32::MAX, ' ', "/", u8::MAX).unwrap();
+/// 
+/// assert_eq!(n, buff.len());
+/// ```
+#[macro_export]
+macro_rules! buf {
+    ($($r:tt)+) => {
+        [0u8; $crate::max_len!($($r)+)]
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::{str_len, uint_len, int_len};
+
+    const STR: usize = str_len("abc");
+    const UINT: usize = uint_len(999);
+    const INT: usize = int_len(-1000);
+
+    #[test]
+    fn const_lengths() {
+        assert_eq!(STR, 3);
+        assert_eq!(UINT, 3);
+        assert_eq!(INT, 5);
+
+        assert_eq!(uint_len(0), 1);
+        assert_eq!(uint_len(u64::MAX as u128), 20);
+        assert_eq!(uint_len(u128::MAX), 39);
+        assert_eq!(int_len(0), 1);
+        assert_eq!(int_len(i64::MIN as i128), 20);
+        assert_eq!(int_len(i128::MIN), 40);
+    }
+
+    #[test]
+    fn const_buffer() {
+        let buff = [0u8; uint_len(12345)];
+        assert_eq!(buff.len(), 5);
+    }
+
+    #[test]
+    fn max_lengths() {
+        use super::MaxLen;
+
+        assert_eq!(u8::MAX_LEN, 3);
+        assert_eq!(u32::MAX_LEN, 10);
+        assert_eq!(u64::MAX_LEN, 20);
+        assert_eq!(u128::MAX_LEN, 39);
+        assert_eq!(i8::MAX_LEN, 4);
+        assert_eq!(i32::MAX_LEN, 11);
+        assert_eq!(i64::MAX_LEN, 20);
+        assert_eq!(char::MAX_LEN, 4);
+
+        const N: usize = crate::max_len!(u32, ' ', "/", u8, 'µ', i16, (), "abc",);
+        assert_eq!(N, 10 + 1 + 1 + 3 + 2 + 6 + 3);
+    }
+
+    #[test]
+    fn buf_max_values() {
+        let mut buff = crate::buf!(u8, ' ', u16, ' ', u32, ' ', u64, ' ', u128, ' ', usize);
+        let n = crate::write!(buff, u8::MAX, ' ', u16::MAX, ' ', u32::MAX, ' ', u64::MAX, ' ', u128::MAX, ' ', usize::MAX).unwrap();
+        assert_eq!(n, buff.len());
+
+        let mut buff = crate::buf!(i8, ',', i16, ',', i32, ',', i64, ',', i128, ',', isize);
+        crate::write!(buff, i8::MAX, ',', i16::MAX, ',', i32::MAX, ',', i64::MAX, ',', i128::MAX, ',', isize::MAX).unwrap();
+        crate::write!(buff, i8::MIN + 1, ',', i16::MIN + 1, ',', i32::MIN + 1, ',', i64::MIN + 1, ',', i128::MIN + 1, ',', isize::MIN + 1).unwrap();
+
+        let mut buff = crate::buf!("id=", u32, char, "/", u8);
+        let n = crate::write!(buff, "id=", u32::MAX, '🦀', "/", u8::MAX).unwrap();
+        assert_eq!(n, buff.len());
+
+        // Typed buffers
+        let buff: [u8; crate::max_len!(u16, ':', u16)] = [0u8; 11];
+        assert_eq!(buff.len(), 11);
+    }
+}
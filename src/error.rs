@@ -2,9 +2,16 @@
 /// Error type for string encoding
 #[derive(Clone, Copy, PartialEq, Debug)]
 #[cfg_attr(feature = "thiserror", derive(thiserror::Error))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error {
     #[cfg_attr(feature = "thiserror", error("buffer length"))]
     BufferLength,
     #[cfg_attr(feature = "thiserror", error("invalid utf8"))]
     InvalidUtf8,
+    #[cfg_attr(feature = "thiserror", error("value overflow"))]
+    Overflow,
+    #[cfg_attr(feature = "thiserror", error("value exceeds maximum width"))]
+    TooWide,
+    #[cfg_attr(feature = "thiserror", error("invalid input"))]
+    InvalidInput,
 }
@@ -0,0 +1,154 @@
+//! [Ansi] helpers for colouring terminal output with ANSI SGR escape sequences
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Ansi, Color, ColorNum}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let a = Ansi::new(Color::Green, "ok");
+//! let s = a.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "\x1b[32mok\x1b[0m");
+//! 
+//! let c = ColorNum::new(95u8, 90);
+//! let s = c.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "\x1b[31m95\x1b[0m");
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// SGR sequence to reset all attributes
+const RESET: &str = "\x1b[0m";
+
+/// ANSI foreground colours
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// Fetch the SGR parameter for the colour
+    pub const fn sgr(&self) -> u8 {
+        30 + *self as u8
+    }
+}
+
+/// Helper for wrapping an encodable type in an ANSI SGR colour sequence,
+/// resetting attributes after the inner value
+pub struct Ansi<E: EncodeStr> {
+    color: Color,
+    inner: E,
+}
+
+impl <E: EncodeStr> Ansi<E> {
+    /// Create a new ANSI wrapper with the provided colour and inner encoder
+    pub const fn new(color: Color, inner: E) -> Self {
+        Self{
+            color,
+            inner,
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [Ansi], emitting `ESC[<sgr>m<inner>ESC[0m`
+impl <E: EncodeStr> EncodeStr for Ansi<E> {
+    fn len(&self) -> usize {
+        "\x1b[".len() + self.color.sgr().len() + 1 + self.inner.len() + RESET.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write SGR sequence
+        let mut n = "\x1b[".write(buff)?;
+        n += self.color.sgr().write(&mut buff[n..])?;
+        n += 'm'.write(&mut buff[n..])?;
+
+        // Write inner value
+        n += self.inner.write(&mut buff[n..])?;
+
+        // Reset attributes
+        n += RESET.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.inner.validate()
+    }
+}
+
+/// Helper for encoding numbers in green when below a threshold and red when at or above it
+pub struct ColorNum<N: EncodeStr>(Ansi<N>);
+
+impl <N: EncodeStr + PartialOrd> ColorNum<N> {
+    /// Create a new colour coded number, red when `value >= threshold`
+    pub fn new(value: N, threshold: N) -> Self {
+        let color = match value < threshold {
+            true => Color::Green,
+            false => Color::Red,
+        };
+
+        Self(Ansi::new(color, value))
+    }
+}
+
+/// [EncodeStr] implementation for [ColorNum]
+impl <N: EncodeStr> EncodeStr for ColorNum<N> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        self.0.write(buff)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.0.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::{Ansi, Color, ColorNum};
+
+    #[test]
+    fn encode_ansi() {
+        let a = Ansi::new(Color::Blue, "abc");
+
+        let mut buff = [0u8; 32];
+        let s = a.write_str(&mut buff).unwrap();
+
+        assert_eq!(s, "\x1b[34mabc\x1b[0m");
+        assert_eq!(a.len(), s.len());
+    }
+
+    #[test]
+    fn color_num_threshold() {
+        let tests = &[
+            (12i32, 50, "\x1b[32m12\x1b[0m"),
+            (50i32, 50, "\x1b[31m50\x1b[0m"),
+            (-3i32, 0, "\x1b[32m-3\x1b[0m"),
+        ];
+
+        for (v, t, s) in tests {
+            let c = ColorNum::new(*v, *t);
+
+            assert_eq!(c.len(), s.len());
+
+            let mut buff = [0u8; 32];
+            assert_eq!(c.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
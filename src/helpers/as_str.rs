@@ -0,0 +1,66 @@
+//! [Str] helper for encoding any `AsRef<str>` string type
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Str};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let name: &str = "abc";
+//! let s = emstr::write_str!(buff, "name=", Str(name)).unwrap();
+//! 
+//! assert_eq!(s, "name=abc");
+//! ```
+
+use crate::{EncodeStr, Error, WriteMode};
+
+/// Wrapper type for encoding string types implementing [`AsRef<str>`]
+/// (such as `heapless::String` or `arrayvec::ArrayString`), without
+/// conflicting with existing [EncodeStr] implementations
+pub struct Str<T: AsRef<str>>(pub T);
+
+/// [EncodeStr] implementation for [Str]
+impl <T: AsRef<str>> EncodeStr for Str<T> {
+    fn len(&self) -> usize {
+        self.0.as_ref().len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        self.0.as_ref().write(buff)
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        self.0.as_ref().write_with(buff, mode)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error};
+    use super::Str;
+
+    #[test]
+    fn encode_str_ref() {
+        let mut buff = [0u8; 32];
+
+        let s = Str("abc");
+        assert_eq!(s.len(), 3);
+        assert_eq!(s.write_str(&mut buff), Ok("abc"));
+
+        assert_eq!(Str("").write_str(&mut buff), Ok(""));
+        assert_eq!(Str("abcd").write(&mut buff[..3]), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn encode_heapless_string() {
+        let mut buff = [0u8; 32];
+
+        let mut v = heapless::String::<16>::new();
+        v.push_str("µs=").unwrap();
+        v.push_str("12").unwrap();
+
+        assert_eq!(Str(&v).len(), 6);
+        assert_eq!(Str(&v).write_str(&mut buff), Ok("µs=12"));
+
+        let n = crate::write!(buff, '[', Str(v), ']').unwrap();
+        assert_eq!(&buff[..n], "[µs=12]".as_bytes());
+    }
+}
@@ -0,0 +1,116 @@
+//! [Base64] and [Base64Url] helpers for displaying byte arrays as base64
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Base64};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let b = Base64(b"hello");
+//! let s = b.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "aGVsbG8=");
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Standard base64 alphabet (RFC 4648 §4)
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// URL and filename safe base64 alphabet (RFC 4648 §5)
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Wrapper type for encoding byte arrays as standard base64 with `=` padding
+pub struct Base64<B: AsRef<[u8]>>(pub B);
+
+/// Wrapper type for encoding byte arrays as URL-safe base64 with `=` padding
+pub struct Base64Url<B: AsRef<[u8]>>(pub B);
+
+/// Encoded length of `n` bytes of base64
+const fn encoded_len(n: usize) -> usize {
+    n.div_ceil(3) * 4
+}
+
+/// Encode bytes to base64 using the provided alphabet
+fn encode(b: &[u8], alphabet: &[u8; 64], buff: &mut [u8]) -> Result<usize, Error> {
+    let n = encoded_len(b.len());
+
+    // Check buffer length
+    if buff.len() < n {
+        return Err(Error::BufferLength)
+    }
+
+    // Write out each 3 byte chunk as 4 characters, padding partial chunks
+    for (c, o) in b.chunks(3).zip(buff.chunks_mut(4)) {
+        let v = (c[0] as u32) << 16
+            | (*c.get(1).unwrap_or(&0) as u32) << 8
+            | *c.get(2).unwrap_or(&0) as u32;
+
+        for (i, d) in o.iter_mut().enumerate() {
+            *d = match i <= c.len() {
+                true => alphabet[(v >> (18 - i * 6)) as usize & 0x3F],
+                false => b'=',
+            };
+        }
+    }
+
+    Ok(n)
+}
+
+/// [EncodeStr] implementation to write bytes as standard base64
+impl <B: AsRef<[u8]>> EncodeStr for Base64<B> {
+    fn len(&self) -> usize {
+        encoded_len(self.0.as_ref().len())
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        encode(self.0.as_ref(), STANDARD, buff)
+    }
+}
+
+/// [EncodeStr] implementation to write bytes as URL-safe base64
+impl <B: AsRef<[u8]>> EncodeStr for Base64Url<B> {
+    fn len(&self) -> usize {
+        encoded_len(self.0.as_ref().len())
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        encode(self.0.as_ref(), URL_SAFE, buff)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::{Base64, Base64Url};
+
+    #[test]
+    fn encode_base64() {
+        // RFC 4648 §10 test vectors
+        let tests: &[(&[u8], &str)] = &[
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"fooba", "Zm9vYmE="),
+            (b"foobar", "Zm9vYmFy"),
+            (&[0xfb, 0xff, 0xbf], "+/+/"),
+        ];
+
+        for (v, s) in tests {
+            let b = Base64(v);
+
+            assert_eq!(b.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(b.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_base64_url() {
+        let b = Base64Url([0xfb, 0xff, 0xbf, 0xfe]);
+
+        let mut buff = [0u8; 32];
+        assert_eq!(b.write_str(&mut buff), Ok("-_-__g=="));
+    }
+}
@@ -0,0 +1,84 @@
+//! [BoolSummary] helper for displaying counts of set flags
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::BoolSummary};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let b = BoolSummary::new(&[true, false, true, true, false]);
+//! let s = b.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "3/5 set");
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Helper for encoding the number of set flags over the total (`n/m set`)
+pub struct BoolSummary<'a> {
+    flags: &'a [bool],
+    suffix: &'a str,
+}
+
+impl <'a> BoolSummary<'a> {
+    /// Create a new flag summary using the default ` set` suffix
+    pub const fn new(flags: &'a [bool]) -> Self {
+        Self::with_suffix(flags, " set")
+    }
+
+    /// Create a new flag summary with the provided suffix
+    pub const fn with_suffix(flags: &'a [bool], suffix: &'a str) -> Self {
+        Self{
+            flags,
+            suffix,
+        }
+    }
+
+    /// Count the number of set flags
+    fn count(&self) -> usize {
+        self.flags.iter().filter(|f| **f).count()
+    }
+}
+
+/// [EncodeStr] implementation for [BoolSummary]
+impl <'a> EncodeStr for BoolSummary<'a> {
+    fn len(&self) -> usize {
+        self.count().len() + 1 + self.flags.len().len() + self.suffix.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write count / total and suffix
+        let mut n = self.count().write(buff)?;
+        n += '/'.write(&mut buff[n..])?;
+        n += self.flags.len().write(&mut buff[n..])?;
+        n += self.suffix.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::BoolSummary;
+
+    #[test]
+    fn encode_bool_summary() {
+        let tests = &[
+            (BoolSummary::new(&[true, false, true, true, false]), "3/5 set"),
+            (BoolSummary::new(&[false, false, false]), "0/3 set"),
+            (BoolSummary::new(&[]), "0/0 set"),
+            (BoolSummary::with_suffix(&[true; 12], " enabled"), "12/12 enabled"),
+        ];
+
+        for (b, s) in tests {
+            assert_eq!(b.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(b.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
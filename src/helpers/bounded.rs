@@ -0,0 +1,226 @@
+//! [Bounded] helper for guarding the maximum width of encoded values
+//! 
+//! ```
+//! # use emstr::{EncodeStr, Error, helpers::Bounded};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let b = Bounded::new(1234u32, 4);
+//! assert_eq!(b.write_str(&mut buff), Ok("1234"));
+//! 
+//! let b = Bounded::new(12345u32, 4);
+//! # #[cfg(debug_assertions)]
+//! assert_eq!(b.write_str(&mut buff), Err(Error::TooWide));
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Scratch length for chunked encoding when truncating values that do not fit the output buffer
+const SCRATCH_LEN: usize = 64;
+
+/// Helper for bounding the encoded width of an inner value.
+/// 
+/// Values wider than `max` return [Error::TooWide], unless truncation is
+/// enabled with [Bounded::truncate] in which case release builds truncate
+/// the output to `max` bytes (at a character boundary). Debug builds always
+/// return an error to catch layout bugs early.
+pub struct Bounded<E: EncodeStr> {
+    inner: E,
+    max: usize,
+    truncate: bool,
+}
+
+impl <E: EncodeStr> Bounded<E> {
+    /// Create a new bounded wrapper with the provided maximum width
+    pub const fn new(inner: E, max: usize) -> Self {
+        Self{ inner, max, truncate: false }
+    }
+
+    /// Truncate over-width values in release builds rather than returning an error
+    pub const fn truncate(mut self) -> Self {
+        self.truncate = true;
+        self
+    }
+
+    /// Check whether over-width values are truncated in this build
+    fn truncating(&self) -> bool {
+        self.truncate && !cfg!(debug_assertions)
+    }
+
+    /// Stream the inner value truncated to `max` bytes at a character boundary,
+    /// copying to `buff` where provided, returning the truncated length.
+    /// 
+    /// `buff` must be at least the truncated length.
+    fn stream_truncated(&self, mut buff: Option<&mut [u8]>) -> Result<usize, Error> {
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let (mut n, mut done) = (0, false);
+
+        self.inner.write_chunks(&mut scratch, |chunk| {
+            if done {
+                return Ok(())
+            }
+
+            // Back off to the nearest character boundary
+            let mut c = chunk.len().min(self.max - n);
+            while !chunk.is_char_boundary(c) {
+                c -= 1;
+            }
+            done = c < chunk.len();
+
+            if let Some(b) = buff.as_deref_mut() {
+                b[n..][..c].copy_from_slice(&chunk.as_bytes()[..c]);
+            }
+            n += c;
+
+            Ok(())
+        })?;
+
+        Ok(n)
+    }
+
+    /// Write the inner value truncated to `max` bytes
+    fn write_truncated(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let l = self.inner.len();
+
+        // Encode in place where the buffer fits the whole value
+        if buff.len() >= l {
+            let l = self.inner.write(buff)?;
+
+            // Back off to the nearest character boundary
+            let mut n = self.max.min(l);
+            while n > 0 && n < l && (buff[n] & 0xc0) == 0x80 {
+                n -= 1;
+            }
+
+            return Ok(n)
+        }
+
+        // Otherwise stream chunks up to the truncated length
+        let n = self.stream_truncated(None)?;
+
+        // Check buffer length
+        if buff.len() < n {
+            return Err(Error::BufferLength)
+        }
+
+        self.stream_truncated(Some(buff))
+    }
+}
+
+/// [EncodeStr] implementation for [Bounded]
+impl <E: EncodeStr> EncodeStr for Bounded<E> {
+    fn len(&self) -> usize {
+        let l = self.inner.len();
+        if l <= self.max || !self.truncating() {
+            return l;
+        }
+
+        self.stream_truncated(None).unwrap_or(self.max)
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let l = self.inner.len();
+
+        // Check bounds
+        if l > self.max {
+            return match self.truncating() {
+                true => self.write_truncated(buff),
+                false => Err(Error::TooWide),
+            }
+        }
+
+        self.inner.write(buff)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.inner.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_bounded() {
+        let mut buff = [0u8; 32];
+
+        // Under
+        let b = Bounded::new("abc", 4);
+        assert_eq!(b.len(), 3);
+        assert_eq!(b.write_str(&mut buff), Ok("abc"));
+
+        // Equal
+        let b = Bounded::new(1234u32, 4);
+        assert_eq!(b.len(), 4);
+        assert_eq!(b.write_str(&mut buff), Ok("1234"));
+
+        // Over
+        let b = Bounded::new(12345u32, 4);
+        assert_eq!(b.len(), 5);
+        assert_eq!(b.write_str(&mut buff), Err(Error::TooWide));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn bounded_debug_errors() {
+        let mut buff = [0u8; 32];
+
+        let b = Bounded::new("abcdef", 4).truncate();
+        assert_eq!(b.write_str(&mut buff), Err(Error::TooWide));
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn bounded_release_truncates() {
+        let mut buff = [0u8; 80];
+        let s = "0123456789".repeat(10);
+
+        let b = Bounded::new(s.as_str(), 70).truncate();
+        assert_eq!(b.len(), 70);
+        assert_eq!(b.write_str(&mut buff), Ok(&s[..70]));
+    }
+
+    #[test]
+    fn bounded_truncation() {
+        let mut buff = [0u8; 32];
+
+        let b = Bounded::new("abcdef", 4).truncate();
+        assert_eq!(b.write_truncated(&mut buff), Ok(4));
+        assert_eq!(&buff[..4], b"abcd");
+
+        // Truncation backs off to a character boundary
+        let b = Bounded::new("ab\u{00b5}c", 3).truncate();
+        assert_eq!(b.write_truncated(&mut buff), Ok(2));
+        assert_eq!(&buff[..2], b"ab");
+    }
+
+    #[test]
+    fn bounded_truncation_long() {
+        let s = "0123456789".repeat(10);
+
+        // Inner value larger than the chunk scratch, in place
+        let mut buff = [0u8; 128];
+        let b = Bounded::new(s.as_str(), 70).truncate();
+        assert_eq!(b.stream_truncated(None), Ok(70));
+        assert_eq!(b.write_truncated(&mut buff), Ok(70));
+        assert_eq!(&buff[..70], &s.as_bytes()[..70]);
+
+        // Streamed where the buffer only fits the truncated value
+        let mut buff = [0u8; 80];
+        assert_eq!(b.write_truncated(&mut buff), Ok(70));
+        assert_eq!(&buff[..70], &s.as_bytes()[..70]);
+
+        let mut buff = [0u8; 60];
+        assert_eq!(b.write_truncated(&mut buff), Err(Error::BufferLength));
+
+        // Streamed across chunks, backing off to a character boundary
+        let mut buff = [0u8; 80];
+        let b = Bounded::new((s.as_str(), "\u{00b5}\u{00b5}"), 101).truncate();
+        assert_eq!(b.stream_truncated(None), Ok(100));
+        assert_eq!(b.write_truncated(&mut buff[..]), Err(Error::BufferLength));
+
+        let mut buff = [0u8; 102];
+        assert_eq!(b.write_truncated(&mut buff[..101]), Ok(100));
+        assert_eq!(&buff[..100], s.as_bytes());
+    }
+}
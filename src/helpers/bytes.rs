@@ -0,0 +1,130 @@
+//! [Bytes] helper for writing raw byte arrays, and [AsciiByte] for single ASCII bytes
+//! 
+//! ```
+//! # use emstr::{EncodeStr, Error, helpers::{Bytes, AsciiByte}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let b = Bytes(b"abc");
+//! assert_eq!(b.write_str(&mut buff), Ok("abc"));
+//! 
+//! // Invalid UTF-8 is detected prior to writing
+//! let b = Bytes(&[0xff, 0xfe]);
+//! assert_eq!(b.write_str(&mut buff), Err(Error::InvalidUtf8));
+//! 
+//! // Single bytes written as characters rather than numbers
+//! let s = emstr::write_str!(buff, "status=", AsciiByte(b'A')).unwrap();
+//! assert_eq!(s, "status=A");
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Wrapper type for writing byte arrays verbatim.
+/// 
+/// Content is not required to be valid UTF-8, [EncodeStr::validate] is
+/// implemented so [EncodeStr::write_str] fails prior to modifying the buffer.
+pub struct Bytes<B: AsRef<[u8]>>(pub B);
+
+/// [EncodeStr] implementation to write raw bytes
+impl <B: AsRef<[u8]>> EncodeStr for Bytes<B> {
+    fn len(&self) -> usize {
+        self.0.as_ref().len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let b = self.0.as_ref();
+
+        // Check buffer length
+        if buff.len() < b.len() {
+            return Err(Error::BufferLength)
+        }
+
+        buff[..b.len()].copy_from_slice(b);
+
+        Ok(b.len())
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        core::str::from_utf8(self.0.as_ref()).map(|_| ()).map_err(|_| Error::InvalidUtf8)
+    }
+}
+
+/// Wrapper type for writing a single byte verbatim as an ASCII character
+/// (`AsciiByte(b'A')` -> `A`), rather than as a number.
+/// 
+/// Bytes outside of the ASCII range are not valid UTF-8, [EncodeStr::validate]
+/// is implemented so [EncodeStr::write_str] fails with [Error::InvalidUtf8].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AsciiByte(pub u8);
+
+/// [EncodeStr] implementation to write a raw byte
+impl EncodeStr for AsciiByte {
+    fn len(&self) -> usize {
+        1
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.is_empty() {
+            return Err(Error::BufferLength)
+        }
+
+        buff[0] = self.0;
+
+        Ok(1)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        match self.0.is_ascii() {
+            true => Ok(()),
+            false => Err(Error::InvalidUtf8),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error};
+    use super::{Bytes, AsciiByte};
+
+    #[test]
+    fn encode_bytes() {
+        let b = Bytes([b'a', b'b', 0xc2, 0xb5]);
+
+        let mut buff = [0u8; 32];
+        assert_eq!(b.write_str(&mut buff), Ok("abµ"));
+        assert_eq!(b.len(), 4);
+    }
+
+    #[test]
+    fn bytes_invalid_utf8() {
+        let b = Bytes(&[b'a', 0xff, b'b']);
+
+        let mut buff = [b'x'; 8];
+        assert_eq!(b.validate(), Err(Error::InvalidUtf8));
+        assert_eq!(b.write_str(&mut buff), Err(Error::InvalidUtf8));
+        assert_eq!(&buff, b"xxxxxxxx");
+
+        // Raw writes are still permitted
+        assert_eq!(b.write(&mut buff), Ok(3));
+    }
+
+    #[test]
+    fn encode_ascii_byte() {
+        let mut buff = [0u8; 32];
+
+        let b = AsciiByte(0x41);
+        assert_eq!(b.len(), 1);
+        assert_eq!(b.write_str(&mut buff), Ok("A"));
+
+        // Distinct from numeric encoding
+        let n = crate::write!(buff, 0x41u8, '/', AsciiByte(0x41)).unwrap();
+        assert_eq!(&buff[..n], b"65/A");
+
+        // Control bytes are written verbatim
+        assert_eq!(AsciiByte(0x07).write_str(&mut buff), Ok("\x07"));
+
+        // Non-ASCII bytes are not valid UTF-8
+        assert_eq!(AsciiByte(0xff).write_str(&mut buff), Err(Error::InvalidUtf8));
+        assert_eq!(AsciiByte(b'A').write(&mut []), Err(Error::BufferLength));
+    }
+}
@@ -0,0 +1,144 @@
+//! [AsciiLower], [AsciiUpper], and [Capitalize] helpers for ASCII case conversion
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{AsciiLower, AsciiUpper, Capitalize}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! assert_eq!(AsciiLower("Some_ID").write_str(&mut buff), Ok("some_id"));
+//! assert_eq!(AsciiUpper("Some_ID").write_str(&mut buff), Ok("SOME_ID"));
+//! assert_eq!(Capitalize("status").write_str(&mut buff), Ok("Status"));
+//! ```
+//! 
+//! Only ASCII letters are converted, non-ASCII bytes are passed through unchanged.
+
+use crate::{EncodeStr, Error};
+
+/// Wrapper type for encoding strings with ASCII letters converted to lowercase
+pub struct AsciiLower<'a>(pub &'a str);
+
+/// Wrapper type for encoding strings with ASCII letters converted to uppercase
+pub struct AsciiUpper<'a>(pub &'a str);
+
+/// Wrapper type for encoding strings with the first alphabetic character
+/// converted to uppercase where this is an ASCII letter
+pub struct Capitalize<'a>(pub &'a str);
+
+/// Copy a string into the buffer, applying the provided byte transformation
+fn write_mapped(s: &str, buff: &mut [u8], f: impl Fn(&u8) -> u8) -> Result<usize, Error> {
+    let b = s.as_bytes();
+
+    // Check buffer length
+    if buff.len() < b.len() {
+        return Err(Error::BufferLength)
+    }
+
+    for (o, i) in buff.iter_mut().zip(b) {
+        *o = f(i);
+    }
+
+    Ok(b.len())
+}
+
+/// [EncodeStr] implementation for [AsciiLower]
+impl <'a> EncodeStr for AsciiLower<'a> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        write_mapped(self.0, buff, u8::to_ascii_lowercase)
+    }
+}
+
+/// [EncodeStr] implementation for [AsciiUpper]
+impl <'a> EncodeStr for AsciiUpper<'a> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        write_mapped(self.0, buff, u8::to_ascii_uppercase)
+    }
+}
+
+/// [EncodeStr] implementation for [Capitalize]
+impl <'a> EncodeStr for Capitalize<'a> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Locate the first alphabetic character
+        let first = self.0.char_indices().find(|(_, c)| c.is_alphabetic()).map(|(i, _)| i);
+
+        let n = self.0.write(buff)?;
+
+        // Uppercase if ASCII, other characters are left unchanged
+        if let Some(i) = first {
+            buff[i] = buff[i].to_ascii_uppercase();
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error};
+    use super::{AsciiLower, AsciiUpper, Capitalize};
+
+    #[test]
+    fn encode_ascii_lower() {
+        let tests = &[
+            ("MixedCase", "mixedcase"),
+            ("ABC-123_!?", "abc-123_!?"),
+            ("ÀBÇ", "ÀbÇ"),
+        ];
+
+        for (v, s) in tests {
+            let mut buff = [0u8; 32];
+            assert_eq!(AsciiLower(v).len(), s.len());
+            assert_eq!(AsciiLower(v).write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_ascii_upper() {
+        let tests = &[
+            ("MixedCase", "MIXEDCASE"),
+            ("abc-123_!?", "ABC-123_!?"),
+            ("µs", "µS"),
+        ];
+
+        for (v, s) in tests {
+            let mut buff = [0u8; 32];
+            assert_eq!(AsciiUpper(v).len(), s.len());
+            assert_eq!(AsciiUpper(v).write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_capitalize() {
+        let tests = &[
+            ("status", "Status"),
+            ("Status", "Status"),
+            (" leading", " Leading"),
+            ("-- dash", "-- Dash"),
+            ("123abc", "123Abc"),
+            ("élan", "élan"),
+            ("", ""),
+        ];
+
+        for (v, s) in tests {
+            let mut buff = [0u8; 32];
+            assert_eq!(Capitalize(v).len(), s.len());
+            assert_eq!(Capitalize(v).write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn ascii_case_buffer_length() {
+        let mut buff = [0u8; 2];
+        assert_eq!(AsciiLower("abc").write(&mut buff), Err(Error::BufferLength));
+    }
+}
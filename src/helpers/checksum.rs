@@ -0,0 +1,125 @@
+//! [WithChecksum] helper for appending a one byte checksum to encoded values
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::WithChecksum};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let c = WithChecksum::new("abc");
+//! assert_eq!(c.write_str(&mut buff).unwrap(), "abc60");
+//! 
+//! let c = WithChecksum::new("abc").sum();
+//! assert_eq!(c.write_str(&mut buff).unwrap(), "abc26");
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::hex::HEX_MAP_UPPER;
+
+/// Helper for encoding an inner value followed by a one byte checksum over
+/// the encoded bytes, rendered as two uppercase hex characters (`XOR` by default)
+pub struct WithChecksum<E: EncodeStr> {
+    inner: E,
+    kind: Checksum,
+}
+
+/// Checksum algorithm
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Checksum {
+    /// XOR of all bytes (as used by NMEA 0183)
+    Xor,
+    /// Sum of all bytes modulo 256
+    Sum,
+}
+
+impl <E: EncodeStr> WithChecksum<E> {
+    /// Create a new checksum wrapper using an XOR checksum
+    pub const fn new(inner: E) -> Self {
+        Self{
+            inner,
+            kind: Checksum::Xor,
+        }
+    }
+
+    /// Use a sum modulo 256 checksum in place of XOR
+    pub const fn sum(mut self) -> Self {
+        self.kind = Checksum::Sum;
+        self
+    }
+
+    /// Compute the checksum over the provided bytes
+    fn checksum(&self, data: &[u8]) -> u8 {
+        match self.kind {
+            Checksum::Xor => data.iter().fold(0, |a, b| a ^ b),
+            Checksum::Sum => data.iter().fold(0, |a, b| a.wrapping_add(*b)),
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [WithChecksum]
+impl <E: EncodeStr> EncodeStr for WithChecksum<E> {
+    fn len(&self) -> usize {
+        self.inner.len() + 2
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write inner value and compute checksum over output
+        let n = self.inner.write(buff)?;
+        let c = self.checksum(&buff[..n]) as usize;
+
+        buff[n] = HEX_MAP_UPPER[c >> 4] as u8;
+        buff[n + 1] = HEX_MAP_UPPER[c & 0x0F] as u8;
+
+        Ok(n + 2)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.inner.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_checksum_nmea() {
+        let mut buff = [0u8; 96];
+
+        let p = "GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,";
+        let c = WithChecksum::new(p);
+
+        let s = c.write_str(&mut buff).unwrap();
+        assert_eq!(c.len(), s.len());
+        assert_eq!(&s[p.len()..], "76");
+    }
+
+    #[test]
+    fn encode_checksum_manual() {
+        let mut buff = [0u8; 32];
+
+        let v = ("id=", 1234u16, ';');
+        let b = b"id=1234;";
+
+        // XOR
+        let x = b.iter().fold(0u8, |a, b| a ^ b);
+        let s = WithChecksum::new(v).write_str(&mut buff).unwrap();
+        assert_eq!(u8::from_str_radix(&s[b.len()..], 16), Ok(x));
+
+        // Sum modulo 256
+        let m = (b.iter().map(|b| *b as u32).sum::<u32>() % 256) as u8;
+        let s = WithChecksum::new(v).sum().write_str(&mut buff).unwrap();
+        assert_eq!(u8::from_str_radix(&s[b.len()..], 16), Ok(m));
+    }
+
+    #[test]
+    fn encode_checksum_buffer_length() {
+        let mut buff = [0u8; 4];
+
+        let c = WithChecksum::new("abc");
+        assert_eq!(c.write_str(&mut buff), Err(Error::BufferLength));
+    }
+}
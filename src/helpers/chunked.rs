@@ -0,0 +1,110 @@
+//! [Chunked] helper for inserting separators into encoded values every N characters
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Chunked};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let c = Chunked::new("ABCDEFGHIJKL", 4, '-');
+//! assert_eq!(c.write_str(&mut buff).unwrap(), "ABCD-EFGH-IJKL");
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Helper for encoding an inner value with a separator inserted every `group`
+/// bytes, counting from the start of the value.
+/// 
+/// Groups are measured in bytes so this is intended for ASCII output.
+pub struct Chunked<E: EncodeStr> {
+    inner: E,
+    group: usize,
+    sep: char,
+}
+
+impl <E: EncodeStr> Chunked<E> {
+    /// Create a new chunked wrapper with the provided group size and separator
+    pub const fn new(inner: E, group: usize, sep: char) -> Self {
+        Self{
+            inner,
+            group,
+            sep,
+        }
+    }
+
+    /// Compute the number of separators for an inner value of length `n`
+    fn separators(&self, n: usize) -> usize {
+        match self.group {
+            0 => 0,
+            _ => n.saturating_sub(1) / self.group,
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [Chunked]
+impl <E: EncodeStr> EncodeStr for Chunked<E> {
+    fn len(&self) -> usize {
+        let n = self.inner.len();
+        n + self.separators(n) * self.sep.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write inner value, then spread groups out in place from the end
+        let n = self.inner.write(buff)?;
+        let s = self.sep.len();
+
+        for i in (1..=self.separators(n)).rev() {
+            let start = i * self.group;
+            let end = (start + self.group).min(n);
+            let offset = start + i * s;
+
+            buff.copy_within(start..end, offset);
+            self.sep.write(&mut buff[offset - s..])?;
+        }
+
+        Ok(n + self.separators(n) * s)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.inner.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_chunked() {
+        let tests = &[
+            (Chunked::new("ABCDEFGHIJKL", 4, '-'), "ABCD-EFGH-IJKL"),
+            (Chunked::new("ABCDEFGHIJ", 4, '-'), "ABCD-EFGH-IJ"),
+            (Chunked::new("ABC", 4, '-'), "ABC"),
+            (Chunked::new("ABCD", 4, '-'), "ABCD"),
+            (Chunked::new("", 4, '-'), ""),
+            (Chunked::new("ABCD", 0, '-'), "ABCD"),
+            (Chunked::new("ABCDE", 1, ' '), "A B C D E"),
+        ];
+
+        for (c, s) in tests {
+            let mut buff = [0u8; 32];
+
+            assert_eq!(c.len(), s.len());
+            assert_eq!(c.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_chunked_inner() {
+        let mut buff = [0u8; 32];
+
+        let c = Chunked::new(1234567890u32, 3, ' ');
+        assert_eq!(c.write_str(&mut buff), Ok("123 456 789 0"));
+
+        let c = Chunked::new("ABCDEFGH", 4, '-');
+        assert_eq!(c.write_str(&mut buff[..8]), Err(Error::BufferLength));
+    }
+}
@@ -0,0 +1,116 @@
+//! [Column] helper for laying out aligned `label: value` rows
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Column, NumCol}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let c = Column::new("temp", 5, NumCol::new(23, 3));
+//! assert_eq!(c.write_str(&mut buff), Ok("temp :  23"));
+//! 
+//! let c = Column::new("rh", 5, NumCol::new(45, 3));
+//! assert_eq!(c.write_str(&mut buff), Ok("rh   :  45"));
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::PadRight;
+
+/// Default label / value separator
+const SEPARATOR: &str = ": ";
+
+/// Helper for encoding a label padded to a fixed width, followed by a
+/// separator (`": "` by default) and value, so the values of rows with
+/// the same label width are aligned.
+/// 
+/// Labels wider than the width extend the row rather than being truncated.
+pub struct Column<'a, L: EncodeStr, V: EncodeStr> {
+    label: PadRight<L>,
+    sep: &'a str,
+    value: V,
+}
+
+impl <'a, L: EncodeStr, V: EncodeStr> Column<'a, L, V> {
+    /// Create a new column row, padding the label to `width` characters
+    pub const fn new(label: L, width: usize, value: V) -> Self {
+        Self{
+            label: PadRight::new(label, width, ' '),
+            sep: SEPARATOR,
+            value,
+        }
+    }
+
+    /// Set the label / value separator
+    pub const fn sep(mut self, sep: &'a str) -> Self {
+        self.sep = sep;
+        self
+    }
+}
+
+/// [EncodeStr] implementation for [Column]
+impl <'a, L: EncodeStr, V: EncodeStr> EncodeStr for Column<'a, L, V> {
+    fn len(&self) -> usize {
+        self.label.len() + self.sep.len() + self.value.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = self.label.write(buff)?;
+        n += self.sep.write(&mut buff[n..])?;
+        n += self.value.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.label.validate()?;
+        self.value.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error, helpers::NumCol};
+    use super::Column;
+
+    #[test]
+    fn encode_column() {
+        let tests = &[
+            (Column::new("temp", 6, NumCol::new(23i32, 4)), "temp  :   23"),
+            (Column::new("rh", 6, NumCol::new(45i32, 4)), "rh    :   45"),
+            (Column::new("press", 6, NumCol::new(1013i32, 4)), "press : 1013"),
+            (Column::new("dew", 6, NumCol::new(-2i32, 4)), "dew   :   -2"),
+        ];
+
+        let mut idx = None;
+
+        for (c, s) in tests {
+            assert_eq!(c.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            let v = c.write_str(&mut buff).unwrap();
+            assert_eq!(v, *s);
+
+            // Check value columns are aligned
+            let i = v.find(": ").unwrap();
+            assert_eq!(*idx.get_or_insert(i), i);
+        }
+    }
+
+    #[test]
+    fn encode_column_config() {
+        let mut buff = [0u8; 32];
+
+        let c = Column::new("mode", 6, "auto").sep(" = ");
+        assert_eq!(c.len(), 6 + 3 + 4);
+        assert_eq!(c.write_str(&mut buff), Ok("mode   = auto"));
+
+        // Wide labels extend the row
+        let c = Column::new("voltage", 4, 12u8);
+        assert_eq!(c.write_str(&mut buff), Ok("voltage: 12"));
+
+        assert_eq!(Column::new("a", 4, 1u8).write(&mut buff[..6]), Err(Error::BufferLength));
+    }
+}
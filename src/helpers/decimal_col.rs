@@ -0,0 +1,188 @@
+//! [SignedDecimalCol] helper for aligning signed decimal values in columns
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Fractional, SignedDecimalCol}};
+//! # let mut a = [0u8; 32];
+//! # let mut b = [0u8; 32];
+//! 
+//! let p = SignedDecimalCol::new(Fractional::new(125i32, 10), 3, 2);
+//! let n = SignedDecimalCol::new(Fractional::new(-325i32, 100), 3, 2);
+//! 
+//! assert_eq!(p.write_str(&mut a).unwrap(), "  12.5 ");
+//! assert_eq!(n.write_str(&mut b).unwrap(), "-  3.25");
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::{Fractional, fractional::Number};
+
+/// Scratch length for fractional encoding, exceeding the longest [Fractional]
+/// (a signed 128-bit integer part with 18 decimal places)
+const SCRATCH_LEN: usize = 64;
+
+/// Helper for encoding [Fractional] values in a fixed layout column, with
+/// a reserved leftmost sign column (space for positive values), the integer
+/// part right-aligned to `int_width` and the decimal part left-aligned to
+/// `frac_width` so digits and decimal points line up across rows.
+/// 
+/// Values exceeding the provided widths extend the column.
+pub struct SignedDecimalCol<N: Number> {
+    value: Fractional<N>,
+    int_width: usize,
+    frac_width: usize,
+}
+
+/// Layout of an encoded fractional value
+struct Layout {
+    /// Offset of the first integer digit in the encoded value
+    start: usize,
+    /// Number of integer digits
+    int_digits: usize,
+    /// Number of decimal digits
+    frac_digits: usize,
+}
+
+impl <N: Number> SignedDecimalCol<N> {
+    /// Create a new signed decimal column with the provided value and widths
+    pub const fn new(value: Fractional<N>, int_width: usize, frac_width: usize) -> Self {
+        Self{
+            value,
+            int_width,
+            frac_width,
+        }
+    }
+
+    /// Encode the inner value to scratch and compute the layout
+    fn layout(&self, scratch: &mut [u8; SCRATCH_LEN]) -> Layout {
+        // Scratch fits any fractional value so this cannot fail
+        let n = self.value.write(scratch).unwrap();
+        let s = &scratch[..n];
+
+        let start = match s.first() {
+            Some(b'-') => 1,
+            _ => 0,
+        };
+        let int_digits = s[start..].iter().take_while(|c| **c != b'.').count();
+        let frac_digits = (n - start - int_digits).saturating_sub(1);
+
+        Layout{ start, int_digits, frac_digits }
+    }
+
+    /// Compute column widths for the provided layout
+    fn widths(&self, l: &Layout) -> (usize, usize) {
+        (self.int_width.max(l.int_digits), self.frac_width.max(l.frac_digits))
+    }
+}
+
+/// [EncodeStr] implementation for [SignedDecimalCol]
+impl <N: Number> EncodeStr for SignedDecimalCol<N> {
+    fn len(&self) -> usize {
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let l = self.layout(&mut scratch);
+        let (iw, fw) = self.widths(&l);
+
+        match fw {
+            0 => 1 + iw,
+            _ => 1 + iw + 1 + fw,
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let l = self.layout(&mut scratch);
+        let (iw, fw) = self.widths(&l);
+
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write sign column
+        let mut n = match l.start {
+            0 => ' '.write(buff)?,
+            _ => '-'.write(buff)?,
+        };
+
+        // Write right-aligned integer part
+        for _i in l.int_digits..iw {
+            n += ' '.write(&mut buff[n..])?;
+        }
+        buff[n..][..l.int_digits].copy_from_slice(&scratch[l.start..][..l.int_digits]);
+        n += l.int_digits;
+
+        if fw == 0 {
+            return Ok(n)
+        }
+
+        // Write decimal point, or a space for whole numbers
+        n += match l.frac_digits {
+            0 => ' '.write(&mut buff[n..])?,
+            _ => '.'.write(&mut buff[n..])?,
+        };
+
+        // Write left-aligned decimal part
+        let frac = l.start + l.int_digits + 1;
+        buff[n..][..l.frac_digits].copy_from_slice(&scratch[frac..][..l.frac_digits]);
+        n += l.frac_digits;
+        for _i in l.frac_digits..fw {
+            n += ' '.write(&mut buff[n..])?;
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::{Fractional, SignedDecimalCol};
+
+    #[test]
+    fn encode_signed_decimal_col() {
+        let tests = &[
+            (125i32, 10, "  12.5 "),
+            (-325i32, 100, "-  3.25"),
+            (-5i32, 100, "-  0.05"),
+            (100i32, 1, " 100   "),
+            (-12345i32, 10, "-1234.5 "),
+        ];
+
+        for (v, d, s) in tests {
+            let c = SignedDecimalCol::new(Fractional::new(*v, *d), 3, 2);
+
+            assert_eq!(c.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(c.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn signed_decimal_col_extremes() {
+        let tests = &[
+            (i128::MIN, 1, "-170141183460469231731687303715884105728"),
+            (i128::MIN, 1_000_000_000_000_000_000, "-170141183460469231731.687303715884105728"),
+            (i128::MAX, 1 << 60, " 147573952589676412927.999999999999999999"),
+        ];
+
+        for (v, d, s) in tests {
+            let c = SignedDecimalCol::new(Fractional::new(*v, *d), 3, 0);
+
+            assert_eq!(c.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 64];
+            assert_eq!(c.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn signed_decimal_col_alignment() {
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+
+        let a = SignedDecimalCol::new(Fractional::new(4215i32, 100), 3, 3).write_str(&mut a).unwrap();
+        let b = SignedDecimalCol::new(Fractional::new(-1i32, 1000), 3, 3).write_str(&mut b).unwrap();
+
+        assert_eq!(a.len(), b.len());
+        assert_eq!(a.find('.'), b.find('.'));
+    }
+}
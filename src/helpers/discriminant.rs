@@ -0,0 +1,109 @@
+//! [Discriminant] helper for displaying field-less enums as their numeric value
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Discriminant};
+//! # let mut buff = [0u8; 32];
+//! 
+//! enum State {
+//!     Idle = 1,
+//!     Running = 20,
+//! }
+//! 
+//! emstr::impl_enum_str!(State { Idle, Running });
+//! 
+//! assert_eq!(State::Running.write_str(&mut buff), Ok("Running"));
+//! assert_eq!(Discriminant(State::Running).write_str(&mut buff), Ok("20"));
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Trait for fetching the numeric discriminant of a field-less enum,
+/// implemented by [impl_enum_str!](crate::impl_enum_str)
+pub trait AsDiscriminant {
+    /// Fetch the discriminant value
+    fn discriminant(&self) -> u32;
+}
+
+/// Blanket [AsDiscriminant] implementation for references
+impl <T: AsDiscriminant> AsDiscriminant for &T {
+    fn discriminant(&self) -> u32 {
+        <T as AsDiscriminant>::discriminant(self)
+    }
+}
+
+/// Wrapper type for encoding enums as their numeric discriminant (`as u32`)
+/// rather than their name, for compact output
+pub struct Discriminant<E: AsDiscriminant>(pub E);
+
+/// [EncodeStr] implementation for [Discriminant]
+impl <E: AsDiscriminant> EncodeStr for Discriminant<E> {
+    fn len(&self) -> usize {
+        self.0.discriminant().len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        self.0.discriminant().write(buff)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error};
+    use super::{Discriminant, AsDiscriminant};
+
+    enum Sample {
+        A,
+        LongerVariantName,
+        Mid = 10,
+        Big = 123456,
+    }
+
+    crate::impl_enum_str!(Sample { A, LongerVariantName, Mid, Big });
+
+    #[test]
+    fn encode_enum_name() {
+        let tests = &[
+            (Sample::A, "A"),
+            (Sample::LongerVariantName, "LongerVariantName"),
+            (Sample::Mid, "Mid"),
+            (Sample::Big, "Big"),
+        ];
+
+        for (v, s) in tests {
+            assert_eq!(v.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(v.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_enum_discriminant() {
+        let tests = &[
+            (Sample::A, "0"),
+            (Sample::LongerVariantName, "1"),
+            (Sample::Mid, "10"),
+            (Sample::Big, "123456"),
+        ];
+
+        for (v, s) in tests {
+            let d = Discriminant(v);
+            assert_eq!(d.len(), s.len(), "invalid length for value: {}", s);
+            assert_eq!(v.discriminant(), s.parse::<u32>().unwrap());
+
+            let mut buff = [0u8; 32];
+            assert_eq!(d.write_str(&mut buff), Ok(*s));
+        }
+
+        // Switch between compact and verbose output
+        let mut buff = [0u8; 32];
+        let verbose = false;
+        let n = match verbose {
+            true => crate::write!(buff, "state=", Sample::Big),
+            false => crate::write!(buff, "state=", Discriminant(Sample::Big)),
+        }.unwrap();
+        assert_eq!(&buff[..n], b"state=123456");
+
+        assert_eq!(Discriminant(Sample::Big).write(&mut buff[..5]), Err(Error::BufferLength));
+    }
+}
@@ -0,0 +1,109 @@
+//! [FromDisplay] adapter for encoding [Display] types
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::FromDisplay};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let d = FromDisplay(core::net::Ipv4Addr::LOCALHOST);
+//! assert_eq!(d.write_str(&mut buff).unwrap(), "127.0.0.1");
+//! ```
+
+use core::fmt::{self, Display, Write};
+
+use crate::{EncodeStr, Error};
+
+/// Adapter for encoding any [Display] type via [core::fmt], writing
+/// directly into the output buffer.
+/// 
+/// As the length cannot be known in advance `len()` performs a trial format
+/// (counting bytes without storing them), so each encode formats twice.
+/// Errors returned by the inner [Display] implementation are reported as
+/// [Error::BufferLength].
+pub struct FromDisplay<T: Display>(pub T);
+
+/// [fmt::Write] shim counting formatted bytes
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// [fmt::Write] shim writing into a byte slice
+struct SliceWriter<'a> {
+    buff: &'a mut [u8],
+    n: usize,
+}
+
+impl <'a> Write for SliceWriter<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // Check remaining space
+        if self.buff.len() - self.n < s.len() {
+            return Err(fmt::Error)
+        }
+
+        self.buff[self.n..][..s.len()].copy_from_slice(s.as_bytes());
+        self.n += s.len();
+
+        Ok(())
+    }
+}
+
+/// [EncodeStr] implementation for [FromDisplay]
+impl <T: Display> EncodeStr for FromDisplay<T> {
+    fn len(&self) -> usize {
+        let mut c = Counter(0);
+        let _ = write!(c, "{}", self.0);
+        c.0
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let mut w = SliceWriter{ buff, n: 0 };
+
+        write!(w, "{}", self.0).map_err(|_| Error::BufferLength)?;
+
+        Ok(w.n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Example type with a custom [Display] implementation
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl Display for Point {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "({}, {})", self.x, self.y)
+        }
+    }
+
+    #[test]
+    fn encode_from_display() {
+        let mut buff = [0u8; 32];
+
+        let d = FromDisplay(Point{ x: 12, y: -4 });
+        assert_eq!(d.len(), 8);
+        assert_eq!(d.write_str(&mut buff), Ok("(12, -4)"));
+
+        let s = crate::write_str!(buff, "at: ", d, '!').unwrap();
+        assert_eq!(s, "at: (12, -4)!");
+
+        let d = FromDisplay(1.5f32);
+        assert_eq!(d.write_str(&mut buff), Ok("1.5"));
+    }
+
+    #[test]
+    fn encode_from_display_overflow() {
+        let mut buff = [0u8; 4];
+
+        let d = FromDisplay(Point{ x: 12, y: -4 });
+        assert_eq!(d.write_str(&mut buff), Err(Error::BufferLength));
+    }
+}
@@ -0,0 +1,147 @@
+//! [Ellipsize] helper for truncating strings to a fixed width with an ellipsis
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Ellipsize};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let e = Ellipsize::new("verylongname", 8);
+//! assert_eq!(e.write_str(&mut buff), Ok("veryl..."));
+//! 
+//! let e = Ellipsize::new("short", 8);
+//! assert_eq!(e.write_str(&mut buff), Ok("short"));
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Default ellipsis
+const ELLIPSIS: &str = "...";
+
+/// Helper for truncating strings longer than `width` bytes, replacing the
+/// tail with an ellipsis (`"..."` by default) such that the output fits the width.
+/// 
+/// Truncation occurs at a character boundary, so output may be shorter than
+/// `width` where this would split a multi-byte character. Where the ellipsis
+/// itself exceeds the width it is truncated in place of the value.
+pub struct Ellipsize<'a> {
+    value: &'a str,
+    width: usize,
+    ellipsis: &'a str,
+}
+
+impl <'a> Ellipsize<'a> {
+    /// Create a new ellipsize wrapper with the provided maximum width
+    pub const fn new(value: &'a str, width: usize) -> Self {
+        Self{
+            value,
+            width,
+            ellipsis: ELLIPSIS,
+        }
+    }
+
+    /// Set the ellipsis string (eg. `"…"`)
+    pub const fn ellipsis(mut self, ellipsis: &'a str) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Compute the (value, ellipsis) portions to be written
+    fn parts(&self) -> (&'a str, &'a str) {
+        // Pass through values within the width
+        if self.value.len() <= self.width {
+            return (self.value, "");
+        }
+
+        // Truncate the ellipsis where it exceeds the width
+        if self.ellipsis.len() > self.width {
+            return ("", floor(self.ellipsis, self.width));
+        }
+
+        (floor(self.value, self.width - self.ellipsis.len()), self.ellipsis)
+    }
+}
+
+/// Truncate a string to at most `n` bytes at a character boundary
+fn floor(s: &str, n: usize) -> &str {
+    let mut n = n.min(s.len());
+    while !s.is_char_boundary(n) {
+        n -= 1;
+    }
+    &s[..n]
+}
+
+/// [EncodeStr] implementation for [Ellipsize]
+impl <'a> EncodeStr for Ellipsize<'a> {
+    fn len(&self) -> usize {
+        let (v, e) = self.parts();
+        v.len() + e.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let (v, e) = self.parts();
+
+        let mut n = v.write(buff)?;
+        n += e.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error};
+    use super::Ellipsize;
+
+    #[test]
+    fn encode_ellipsize() {
+        let tests = &[
+            // Shorter than width
+            (Ellipsize::new("abc", 8), "abc"),
+            (Ellipsize::new("", 8), ""),
+            // Exactly width
+            (Ellipsize::new("abcdefgh", 8), "abcdefgh"),
+            // Longer than width
+            (Ellipsize::new("verylongname", 8), "veryl..."),
+            (Ellipsize::new("abcdefghi", 8), "abcde..."),
+            (Ellipsize::new("verylongname", 3), "..."),
+            (Ellipsize::new("verylongname", 2), ".."),
+            (Ellipsize::new("verylongname", 0), ""),
+        ];
+
+        for (e, s) in tests {
+            assert_eq!(e.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(e.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_ellipsize_unicode() {
+        let tests = &[
+            (Ellipsize::new("verylongname", 8).ellipsis("…"), "veryl…"),
+            (Ellipsize::new("verylongname", 8).ellipsis("~"), "verylon~"),
+            // Truncation backs off to a character boundary
+            (Ellipsize::new("abcµµµ", 7), "abc..."),
+            (Ellipsize::new("µµµµµµ", 8), "µµ..."),
+            (Ellipsize::new("verylongname", 2).ellipsis("…"), ""),
+        ];
+
+        for (e, s) in tests {
+            assert_eq!(e.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(e.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn ellipsize_buffer_length() {
+        let mut buff = [0u8; 4];
+        assert_eq!(Ellipsize::new("verylongname", 8).write(&mut buff), Err(Error::BufferLength));
+    }
+}
@@ -0,0 +1,291 @@
+//! [Escape], [JsonStr] and [Quoted] helpers for displaying strings with escape sequences
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Escape, JsonStr, Quoted}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let e = Escape("a\tb\x1b");
+//! let s = e.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "a\\tb\\x1b");
+//! 
+//! let j = JsonStr::quoted("say \"hi\"");
+//! let s = j.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, r#""say \"hi\"""#);
+//! 
+//! let q = Quoted("a, \"b\"");
+//! let s = q.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, r#""a, ""b""""#);
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::hex::HEX_MAP;
+
+/// Wrapper type for encoding strings with control characters (below 0x20
+/// and 0x7f) replaced by C-style escapes (`\n`, `\t`, `\r`, `\0`, `\xNN`)
+pub struct Escape<'a>(pub &'a str);
+
+/// Fetch the shorthand escape character for a byte, if available
+fn shorthand(b: u8) -> Option<u8> {
+    match b {
+        b'\0' => Some(b'0'),
+        b'\t' => Some(b't'),
+        b'\n' => Some(b'n'),
+        b'\r' => Some(b'r'),
+        _ => None,
+    }
+}
+
+/// Encoded length of a single byte
+fn escaped_len(b: u8) -> usize {
+    match b {
+        _ if shorthand(b).is_some() => 2,
+        0x00..=0x1f | 0x7f => 4,
+        _ => 1,
+    }
+}
+
+/// [EncodeStr] implementation for [Escape]
+impl <'a> EncodeStr for Escape<'a> {
+    fn len(&self) -> usize {
+        self.0.bytes().map(escaped_len).sum()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = 0;
+        for b in self.0.bytes() {
+            match (shorthand(b), escaped_len(b)) {
+                // Write shorthand escapes
+                (Some(c), _) => {
+                    buff[n..][..2].copy_from_slice(&[b'\\', c]);
+                },
+                // Write hex escapes
+                (None, 4) => {
+                    buff[n..][..4].copy_from_slice(&[b'\\', b'x', HEX_MAP[(b >> 4) as usize] as u8, HEX_MAP[(b & 0x0F) as usize] as u8]);
+                },
+                // Pass through everything else
+                _ => buff[n] = b,
+            }
+
+            n += escaped_len(b);
+        }
+
+        Ok(n)
+    }
+}
+
+/// Helper for encoding strings with JSON escaping, optionally surrounded by quotes.
+/// 
+/// `"` and `\` are backslash escaped, control characters use shorthand escapes
+/// (`\n`, `\t`, etc.) where available or `\u00XX`, non-ASCII characters are
+/// passed through as UTF-8.
+pub struct JsonStr<'a> {
+    value: &'a str,
+    quoted: bool,
+}
+
+impl <'a> JsonStr<'a> {
+    /// Create a new JSON string body (without surrounding quotes)
+    pub const fn new(value: &'a str) -> Self {
+        Self{
+            value,
+            quoted: false,
+        }
+    }
+
+    /// Create a new JSON string surrounded by quotes
+    pub const fn quoted(value: &'a str) -> Self {
+        Self{
+            value,
+            quoted: true,
+        }
+    }
+
+    /// Fetch the shorthand JSON escape character for a byte, if available
+    fn shorthand(b: u8) -> Option<u8> {
+        match b {
+            b'"' => Some(b'"'),
+            b'\\' => Some(b'\\'),
+            0x08 => Some(b'b'),
+            0x0c => Some(b'f'),
+            b'\n' => Some(b'n'),
+            b'\r' => Some(b'r'),
+            b'\t' => Some(b't'),
+            _ => None,
+        }
+    }
+
+    /// Encoded length of a single byte
+    fn escaped_len(b: u8) -> usize {
+        match b {
+            _ if Self::shorthand(b).is_some() => 2,
+            0x00..=0x1f => 6,
+            _ => 1,
+        }
+    }
+
+    /// Length of the surrounding quotes
+    fn quotes(&self) -> usize {
+        match self.quoted {
+            true => 2,
+            false => 0,
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [JsonStr]
+impl <'a> EncodeStr for JsonStr<'a> {
+    fn len(&self) -> usize {
+        self.value.bytes().map(Self::escaped_len).sum::<usize>() + self.quotes()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = 0;
+
+        // Write opening quote
+        if self.quoted {
+            n += '"'.write(buff)?;
+        }
+
+        for b in self.value.bytes() {
+            match (Self::shorthand(b), Self::escaped_len(b)) {
+                // Write shorthand escapes
+                (Some(c), _) => {
+                    buff[n..][..2].copy_from_slice(&[b'\\', c]);
+                },
+                // Write unicode escapes
+                (None, 6) => {
+                    buff[n..][..6].copy_from_slice(&[b'\\', b'u', b'0', b'0', HEX_MAP[(b >> 4) as usize] as u8, HEX_MAP[(b & 0x0F) as usize] as u8]);
+                },
+                // Pass through everything else
+                _ => buff[n] = b,
+            }
+
+            n += Self::escaped_len(b);
+        }
+
+        // Write closing quote
+        if self.quoted {
+            n += '"'.write(&mut buff[n..])?;
+        }
+
+        Ok(n)
+    }
+}
+
+/// Wrapper type for encoding strings as CSV style quoted fields, surrounded
+/// by `"` with any internal `"` doubled (`a "b"` -> `"a ""b"""`)
+pub struct Quoted<'a>(pub &'a str);
+
+/// [EncodeStr] implementation for [Quoted]
+impl <'a> EncodeStr for Quoted<'a> {
+    fn len(&self) -> usize {
+        self.0.len() + 2 + self.0.bytes().filter(|b| *b == b'"').count()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = '"'.write(buff)?;
+
+        for b in self.0.bytes() {
+            // Double internal quotes
+            if b == b'"' {
+                buff[n] = b'"';
+                n += 1;
+            }
+
+            buff[n] = b;
+            n += 1;
+        }
+
+        n += '"'.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::{Escape, JsonStr, Quoted};
+
+    #[test]
+    fn encode_escape() {
+        let tests = &[
+            ("plain text", "plain text"),
+            ("a\tb", "a\\tb"),
+            ("line\n", "line\\n"),
+            ("\r\n", "\\r\\n"),
+            ("\x1b[0m", "\\x1b[0m"),
+            ("\x7f\0", "\\x7f\\0"),
+            ("µs", "µs"),
+            ("", ""),
+        ];
+
+        for (v, s) in tests {
+            let e = Escape(v);
+
+            assert_eq!(e.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(e.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_json_str() {
+        let tests = &[
+            (JsonStr::new("plain"), "plain"),
+            (JsonStr::new("a \"quote\""), "a \\\"quote\\\""),
+            (JsonStr::new("back\\slash"), "back\\\\slash"),
+            (JsonStr::new("line\n\ttab"), "line\\n\\ttab"),
+            (JsonStr::new("\x01\x1f"), "\\u0001\\u001f"),
+            (JsonStr::new("µs ✓"), "µs ✓"),
+            (JsonStr::quoted("x\"y"), "\"x\\\"y\""),
+            (JsonStr::quoted(""), "\"\""),
+        ];
+
+        for (j, s) in tests {
+            assert_eq!(j.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(j.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_quoted() {
+        let tests = &[
+            ("plain", "\"plain\""),
+            ("value with, comma", "\"value with, comma\""),
+            ("say \"hi\"", "\"say \"\"hi\"\"\""),
+            ("\"", "\"\"\"\""),
+            ("", "\"\""),
+        ];
+
+        for (v, s) in tests {
+            let q = Quoted(v);
+
+            assert_eq!(q.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(q.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! [Flags] helper for displaying set bits by name
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Flags};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let f = Flags::new(0b0101, &["A", "B", "C", "D"]);
+//! assert_eq!(f.write_str(&mut buff).unwrap(), "A|C");
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Helper for encoding the names of set bits in a bitmask, joined with `|`
+/// from the least significant bit.
+/// 
+/// `names[i]` is used for bit `i`, set bits beyond the name table are
+/// encoded by index (`bit7`).
+pub struct Flags<'a> {
+    value: u64,
+    names: &'a [&'a str],
+}
+
+impl <'a> Flags<'a> {
+    /// Create a new flags helper with the provided value and bit name table
+    pub const fn new(value: u64, names: &'a [&'a str]) -> Self {
+        Self{
+            value,
+            names,
+        }
+    }
+
+    /// Iterate over the indices of set bits
+    fn bits(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..64).filter(|i| self.value & (1 << i) != 0)
+    }
+
+    /// Fetch the name for a bit
+    fn name(&self, i: usize) -> Name<'a> {
+        match self.names.get(i) {
+            Some(n) => Name::Named(n),
+            None => Name::Index(i),
+        }
+    }
+}
+
+/// Name for a single flag bit
+enum Name<'a> {
+    Named(&'a str),
+    Index(usize),
+}
+
+/// [EncodeStr] implementation for [Name]
+impl <'a> EncodeStr for Name<'a> {
+    fn len(&self) -> usize {
+        match self {
+            Name::Named(n) => n.len(),
+            Name::Index(i) => 3 + i.len(),
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        match self {
+            Name::Named(n) => n.write(buff),
+            Name::Index(i) => crate::write!(buff[..], "bit", i),
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [Flags]
+impl <'a> EncodeStr for Flags<'a> {
+    fn len(&self) -> usize {
+        let names: usize = self.bits().map(|i| self.name(i).len()).sum();
+        let count = self.bits().count();
+
+        names + count.saturating_sub(1)
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = 0;
+        for (j, i) in self.bits().enumerate() {
+            if j > 0 {
+                n += '|'.write(&mut buff[n..])?;
+            }
+            n += self.name(i).write(&mut buff[n..])?;
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const NAMES: &[&str] = &["A", "B", "C", "D"];
+
+    #[test]
+    fn encode_flags() {
+        let tests = &[
+            (Flags::new(0, NAMES), ""),
+            (Flags::new(0b1111, NAMES), "A|B|C|D"),
+            (Flags::new(0b0101, NAMES), "A|C"),
+            (Flags::new(0b1000, NAMES), "D"),
+            (Flags::new(0b1001_0010, NAMES), "B|bit4|bit7"),
+            (Flags::new(1 << 63, &[]), "bit63"),
+        ];
+
+        for (f, s) in tests {
+            assert_eq!(f.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(f.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! [Float] helper for displaying floating point values with a fixed number of
+//! decimal places, without depending on `core::fmt` float formatting.
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Float};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let f = Float::new(3.14159f32, 2);
+//! let s = f.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "3.14");
+//! ```
+//! 
+//! Values are rounded half away from zero and decimal places are limited to 18,
+//! magnitudes that cannot be represented as an `i128` once scaled will saturate.
+//! 
+//! NaN and infinite values are configurable via [SpecialFloatNames]:
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Float, SpecialFloatNames}};
+//! # let mut buff = [0u8; 32];
+//! let f = Float::new(f32::NAN, 2).names(SpecialFloatNames::JSON);
+//! assert_eq!(f.write_str(&mut buff).unwrap(), "null");
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::Fractional;
+
+/// Maximum number of decimal places for float encoding
+const MAX_DECIMALS: u32 = 18;
+
+/// Textual representations for NaN and infinite float values
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SpecialFloatNames {
+    /// Representation for NaN values
+    pub nan: &'static str,
+    /// Representation for positive infinity
+    pub inf: &'static str,
+    /// Representation for negative infinity
+    pub neg_inf: &'static str,
+}
+
+impl SpecialFloatNames {
+    /// Default representations, `NaN`, `inf` and `-inf`
+    pub const DEFAULT: Self = Self::new("NaN", "inf", "-inf");
+
+    /// JSON compatible representations, `null` for all special values
+    pub const JSON: Self = Self::new("null", "null", "null");
+
+    /// Create a new set of special value representations
+    pub const fn new(nan: &'static str, inf: &'static str, neg_inf: &'static str) -> Self {
+        Self{
+            nan,
+            inf,
+            neg_inf,
+        }
+    }
+
+    /// Fetch the textual representation for NaN and infinite values
+    pub(super) fn special(&self, value: f64) -> Option<&'static str> {
+        if value.is_nan() {
+            Some(self.nan)
+        } else if value.is_infinite() && value.is_sign_negative() {
+            Some(self.neg_inf)
+        } else if value.is_infinite() {
+            Some(self.inf)
+        } else {
+            None
+        }
+    }
+}
+
+/// Default [SpecialFloatNames] implementation, see [SpecialFloatNames::DEFAULT]
+impl Default for SpecialFloatNames {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Helper for encoding floating point values with a fixed number of decimal places,
+/// NaN and infinities are encoded as `NaN`, `inf` and `-inf` by default (see [Float::names])
+pub struct Float {
+    value: f64,
+    decimals: u32,
+    names: SpecialFloatNames,
+}
+
+impl Float {
+    /// Create a new float wrapper with the provided value and number of decimal places
+    pub fn new(value: impl Into<f64>, decimals: u32) -> Self {
+        Self{
+            value: value.into(),
+            decimals: decimals.min(MAX_DECIMALS),
+            names: SpecialFloatNames::DEFAULT,
+        }
+    }
+
+    /// Set the textual representations for NaN and infinite values
+    pub const fn names(mut self, names: SpecialFloatNames) -> Self {
+        self.names = names;
+        self
+    }
+
+    /// Fetch the textual representation for NaN and infinite values
+    fn special(&self) -> Option<&'static str> {
+        self.names.special(self.value)
+    }
+
+    /// Decompose into a [Fractional] scaled by the number of decimal places
+    fn fractional(&self) -> Fractional<i128> {
+        let scale = 10i128.pow(self.decimals);
+
+        // Scale absolute value and round half away from zero
+        let v = match self.value.is_sign_negative() {
+            true => -self.value,
+            false => self.value,
+        } * scale as f64;
+
+        let mut i = v as i128;
+        if v - i as f64 >= 0.5 {
+            i = i.saturating_add(1);
+        }
+
+        match self.value.is_sign_negative() {
+            true => Fractional::fixed(-i, scale),
+            false => Fractional::fixed(i, scale),
+        }
+    }
+
+    /// Check whether a -ve sign is required for values that round to zero
+    fn negative_zero(f: &Fractional<i128>, value: f64) -> bool {
+        value.is_sign_negative() && f.value == 0
+    }
+}
+
+/// [EncodeStr] implementation for [Float]
+impl EncodeStr for Float {
+    fn len(&self) -> usize {
+        if let Some(s) = self.special() {
+            return s.len();
+        }
+
+        let f = self.fractional();
+
+        match Self::negative_zero(&f, self.value) {
+            true => 1 + f.len(),
+            false => f.len(),
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Write NaN and infinite values
+        if let Some(s) = self.special() {
+            return s.write(buff);
+        }
+
+        let f = self.fractional();
+        let mut n = 0;
+
+        // Write -ve sign for values rounding to zero
+        if Self::negative_zero(&f, self.value) {
+            n += '-'.write(buff)?;
+        }
+
+        // Write decimal value
+        n += f.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::{Float, SpecialFloatNames};
+
+    #[test]
+    fn encode_float() {
+        let tests = &[
+            (Float::new(1.5f32, 2), "1.50"),
+            (Float::new(2.0f64, 0), "2"),
+            (Float::new(-0.125f64, 2), "-0.13"),
+            (Float::new(0.999f64, 2), "1.00"),
+            (Float::new(-0.001f64, 2), "-0.00"),
+            (Float::new(-0.0f64, 1), "-0.0"),
+            (Float::new(21.0625f64, 4), "21.0625"),
+            (Float::new(f32::NAN, 2), "NaN"),
+            (Float::new(f32::INFINITY, 2), "inf"),
+            (Float::new(f64::NEG_INFINITY, 2), "-inf"),
+        ];
+
+        for (f, s) in tests {
+            assert_eq!(f.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(f.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_float_names() {
+        let names = SpecialFloatNames::new("nan", "+Infinity", "-Infinity");
+
+        let tests = &[
+            (Float::new(f32::NAN, 2).names(SpecialFloatNames::JSON), "null"),
+            (Float::new(f64::INFINITY, 2).names(SpecialFloatNames::JSON), "null"),
+            (Float::new(f32::NAN, 2).names(names), "nan"),
+            (Float::new(f32::INFINITY, 2).names(names), "+Infinity"),
+            (Float::new(f64::NEG_INFINITY, 2).names(names), "-Infinity"),
+            (Float::new(1.5f32, 2).names(names), "1.50"),
+        ];
+
+        for (f, s) in tests {
+            assert_eq!(f.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(f.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
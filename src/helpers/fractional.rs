@@ -9,6 +9,10 @@
 //! 
 //! assert_eq!(s, "1234.056");
 //! ```
+//! 
+//! Divisors composed only of factors of 2 and 5 (powers of ten, 2, 4, 256, etc.)
+//! are rendered exactly (up to 18 decimal places), other divisors
+//! are truncated to the number of digits in the divisor (`1 / 3` -> `0.3`).
 
 use core::{
     fmt::{Display, Debug},
@@ -17,7 +21,8 @@ use core::{
 
 use num_traits::{PrimInt, Signed, FromPrimitive};
 
-use crate::EncodeStr;
+use crate::{EncodeStr, Error};
+use super::{Grouped, NumberFormat};
 
 /// [Number] trait combines encoding / numeric methods for convenience
 pub trait Number: EncodeStr + PrimInt + Signed + FromPrimitive + Div + Display + Debug + Sized {}
@@ -31,6 +36,9 @@ pub struct Fractional<N: Number> {
     pub value: N,
     /// Divisor to be applied for encoding
     pub divisor: N,
+    /// Emit the full number of decimal places implied by the divisor
+    /// rather than trimming trailing zeroes
+    fixed: bool,
 }
 
 impl <N: Number> Fractional<N> {
@@ -39,90 +47,295 @@ impl <N: Number> Fractional<N> {
         Self{
             value,
             divisor,
+            fixed: false,
         }
     }
-}
 
-impl <N: Number> EncodeStr for Fractional<N> {
-    fn len(&self) -> usize {
-        let int_part = self.value / self.divisor;
-        let dec_part = (self.value % self.divisor).abs();
+    /// Create a new fractional wrapper with the provided value and divisor,
+    /// always emitting the number of decimal places implied by the divisor
+    /// (`Fractional::fixed(1200, 1000)` -> `1.200`)
+    pub const fn fixed(value: N, divisor: N) -> Self {
+        Self{
+            value,
+            divisor,
+            fixed: true,
+        }
+    }
+
+    /// Create a new fixed fractional wrapper with a value scaled by `10^decimals`,
+    /// emitting the provided number of decimal places
+    /// (`Fractional::with_decimals(1234, 3)` -> `1.234`)
+    /// 
+    /// Returns [Error::Overflow] where `10^decimals` cannot be represented by `N`
+    pub fn with_decimals(value: N, decimals: u32) -> Result<Self, Error> {
+        let divisor = 10i128.checked_pow(decimals)
+            .and_then(N::from_i128)
+            .ok_or(Error::Overflow)?;
+
+        Ok(Self::fixed(value, divisor))
+    }
 
-        let mut n = int_part.len();
-        
-        // No decimal part, just display integer
-        if dec_part.is_zero() {
-            return n;
+    /// Create a new fixed fractional wrapper, rounding the value half away
+    /// from zero to the provided number of decimal places
+    /// (`Fractional::rounded(1996, 1000, 2)` -> `2.00`)
+    /// 
+    /// Returns [Error::Overflow] where the rescaled value or divisor cannot
+    /// be represented by `N`, or the divisor is zero
+    pub fn rounded(value: N, divisor: N, decimals: u32) -> Result<Self, Error> {
+        let scale = 10i128.checked_pow(decimals).ok_or(Error::Overflow)?;
+        let v = value.to_i128().ok_or(Error::Overflow)?;
+        let d = divisor.to_i128().filter(|d| *d != 0).ok_or(Error::Overflow)?;
+
+        // Rescale value to the requested decimal places
+        let scaled = v.checked_mul(scale).ok_or(Error::Overflow)?;
+        let mut q = scaled / d;
+
+        // Round remainder half away from zero, carrying into the integer part
+        if (scaled % d).abs() * 2 >= d.abs() {
+            q += if (scaled < 0) != (d < 0) { -1 } else { 1 };
         }
 
-        // Negative integer part, add -ve sign
-        if int_part.is_zero() && self.value.is_negative() {
-            n += 1;
+        let value = N::from_i128(q).ok_or(Error::Overflow)?;
+        let divisor = N::from_i128(scale).ok_or(Error::Overflow)?;
+
+        Ok(Self::fixed(value, divisor))
+    }
+
+    /// Number of decimal places implied by the divisor
+    fn places(&self) -> usize {
+        let divisor = self.divisor.abs().to_u64().unwrap_or(0);
+        if divisor <= 1 {
+            return 0;
+        }
+
+        // Strip factors of 2 and 5 to determine whether the decimal terminates
+        let (mut d, mut twos, mut fives) = (divisor, 0, 0);
+        while d % 2 == 0 {
+            d /= 2;
+            twos += 1;
+        }
+        while d % 5 == 0 {
+            d /= 5;
+            fives += 1;
+        }
+
+        // Terminating decimals need max(twos, fives) places, otherwise
+        // use the divisor digit count to resolve each step of the value
+        let places = match d == 1 {
+            true => twos.max(fives),
+            false => divisor.len(),
+        };
+
+        places.min(MAX_PLACES)
+    }
+
+    /// Compute the (absolute) decimal portion scaled to [Self::places] digits
+    fn decimal(&self) -> u64 {
+        let rem = (self.value % self.divisor).abs().to_i128().unwrap_or(0);
+        let divisor = self.divisor.abs().to_i128().unwrap_or(1);
+
+        (rem * 10i128.pow(self.places() as u32) / divisor) as u64
+    }
+
+    /// Apply a [NumberFormat] for localised decimal and group separators
+    pub const fn with_format(self, format: NumberFormat<'_>) -> FractionalFmt<'_, N> {
+        FractionalFmt{
+            value: self,
+            format,
+        }
+    }
+
+    /// Fetch the integer part of the value, truncated towards zero
+    /// (note the sign of values between -1 and 0 is available via [Self::is_negative])
+    pub fn integer_part(&self) -> N {
+        self.value / self.divisor
+    }
+
+    /// Check whether the value is less than zero
+    pub fn is_negative(&self) -> bool {
+        !self.value.is_zero() && (self.value.is_negative() != self.divisor.is_negative())
+    }
+
+    /// Write the decimal digits (without the decimal point) to the provided buffer,
+    /// zero padded and trimmed as for [EncodeStr::write], returning the number of
+    /// digits written (zero for whole numbers)
+    pub fn decimal_digits(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let dec_part = self.decimal();
+        let places = self.decimal_len();
+
+        // Check buffer length
+        if buff.len() < places {
+            return Err(Error::BufferLength)
+        }
+
+        // No decimal places to write
+        if places == 0 {
+            return Ok(0)
+        }
+
+        // Pad decimal portion with zeroes based on divisor (5 / 100 -> 0.05)
+        let digits = if dec_part == 0 { 0 } else { dec_part.len() };
+        let mut n = 0;
+        for _i in digits..self.places() {
+            n += '0'.write(&mut buff[n..])?;
+        }
+
+        // Zero decimal part is fully represented by padding
+        if dec_part == 0 {
+            return Ok(n)
         }
 
-        // Decimal part, integer + (divisior - 1) + 1
-        n += self.divisor.len();
+        // Trim decimal part
+        let mut d = dec_part;
+        while !self.fixed && d % 10 == 0 {
+            d /= 10;
+        }
+
+        // Write trimmed decimal part
+        n += d.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+
+    /// Number of decimal digits to be written, after trimming
+    fn decimal_len(&self) -> usize {
+        let dec_part = self.decimal();
+        let places = self.places();
+
+        // No decimal places, just display integer
+        if places == 0 || (dec_part == 0 && !self.fixed) {
+            return 0;
+        }
 
         // Trim trailing zeroes
+        let mut n = places;
         let mut d = dec_part;
-        while d % N::from_i8(10).unwrap() == N::zero() {
-            d = d / N::from_i8(10).unwrap();
+        while !self.fixed && d % 10 == 0 {
+            d /= 10;
             n -= 1;
         }
 
         n
     }
 
-    fn write(&self, buff: &mut [u8]) -> Result<usize, crate::Error> {
-        let mut n = 0;
+    /// Check whether a -ve sign is required that won't be written by the integer part
+    fn negative_zero(&self) -> bool {
+        self.integer_part().is_zero() && self.is_negative()
+    }
+}
 
-        // Split integer and decimal components
-        let int_part = self.value / self.divisor;
-        let dec_part = (self.value % self.divisor).abs();
+/// Maximum number of decimal places emitted by [Fractional]
+const MAX_PLACES: usize = 18;
 
-        // Write -ve sign for -ve fractions
-        if int_part.is_zero() && self.value.is_negative() {
-            buff[n] = '-' as u8;
+impl <N: Number> EncodeStr for Fractional<N> {
+    fn len(&self) -> usize {
+        let mut n = self.integer_part().len();
+
+        // Negative integer part, add -ve sign
+        if self.negative_zero() {
             n += 1;
         }
 
+        // Decimal part, '.' + decimal digits
+        match self.decimal_len() {
+            0 => n,
+            d => n + 1 + d,
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = 0;
+
+        // Write -ve sign for -ve fractions
+        if self.negative_zero() {
+            n += '-'.write(&mut buff[n..])?;
+        }
+
         // Write integer part
-        n += int_part.write(&mut buff[n..])?;
+        n += self.integer_part().write(&mut buff[n..])?;
 
         // Skip decimal portion for whole numbers
-        if dec_part.is_zero() {
+        if self.decimal_len() == 0 {
             return Ok(n)
         }
 
         n += '.'.write(&mut buff[n..])?;
 
-        // Pad decimal portion with zeroes based on divisor (5 / 100 -> 0.05)
-        if self.divisor.len() > dec_part.len()  {
-            let padding = self.divisor.len() - dec_part.len() - 1;
-            for _i in 0..padding {
-                buff[n] = '0' as u8;
-                n += 1;
-            }
+        // Write decimal part
+        n += self.decimal_digits(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+/// Helper for encoding [Fractional] values with a [NumberFormat],
+/// see [Fractional::with_format]
+pub struct FractionalFmt<'a, N: Number> {
+    value: Fractional<N>,
+    format: NumberFormat<'a>,
+}
+
+impl <'a, N: Number> FractionalFmt<'a, N> {
+    /// Fetch the grouped integer part
+    fn integer(&self) -> Grouped<'a, N> {
+        Grouped::new(self.value.integer_part()).format(self.format)
+    }
+}
+
+/// [EncodeStr] implementation for [FractionalFmt]
+impl <'a, N: Number> EncodeStr for FractionalFmt<'a, N> {
+    fn len(&self) -> usize {
+        let mut n = self.integer().len();
+
+        // Negative integer part, add -ve sign
+        if self.value.negative_zero() {
+            n += 1;
         }
 
-        // Trim decimal part
-        let mut d = dec_part;
-        while d % N::from_i8(10).unwrap() == N::zero() {
-            d = d / N::from_i8(10).unwrap();
+        // Decimal part, separator + decimal digits
+        match self.value.decimal_len() {
+            0 => n,
+            d => n + self.format.decimal.len() + d,
         }
-        
-        // Write trimmed decimal part
-        n += d.write(&mut buff[n..])?;
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = 0;
+
+        // Write -ve sign for -ve fractions
+        if self.value.negative_zero() {
+            n += '-'.write(&mut buff[n..])?;
+        }
+
+        // Write grouped integer part
+        n += self.integer().write(&mut buff[n..])?;
+
+        // Skip decimal portion for whole numbers
+        if self.value.decimal_len() == 0 {
+            return Ok(n)
+        }
+
+        n += self.format.decimal.write(&mut buff[n..])?;
+        n += self.value.decimal_digits(&mut buff[n..])?;
 
         Ok(n)
     }
 }
 
-
 #[cfg(test)]
 mod test {
-    use crate::EncodeStr;
-    use super::{Fractional, Number};
+    use crate::{EncodeStr, Error};
+    use super::{Fractional, Number, NumberFormat};
 
     #[test]
     fn fractional_i16() {
@@ -177,6 +390,184 @@ mod test {
         encode_frac::<i64>(tests);
     }
 
+    #[test]
+    fn fractional_divisors() {
+        let tests = &[
+            (1, 2, "0.5"),
+            (3, 4, "0.75"),
+            (-3, 4, "-0.75"),
+            (384, 256, "1.5"),
+            (1, 256, "0.00390625"),
+            (1, 3, "0.3"),
+            (2, 3, "0.6"),
+            (-1, 3, "-0.3"),
+            (100, 3, "33.3"),
+            (1, 12, "0.08"),
+            (5, 1, "5"),
+        ];
+
+        encode_frac::<i32>(tests);
+    }
+
+    #[test]
+    fn fractional_fixed() {
+        let tests = &[
+            (100, 100, "1.00"),
+            (0, 100, "0.00"),
+            (105, 100, "1.05"),
+            (120, 100, "1.20"),
+            (5, 1000, "0.005"),
+            (1050, 1000, "1.050"),
+            (-100, 100, "-1.00"),
+            (-5, 100, "-0.05"),
+            (-120, 100, "-1.20"),
+            (42, 1, "42"),
+            (4, 4, "1.00"),
+            (1, 3, "0.3"),
+        ];
+
+        for (v, d, s) in tests {
+            let f = Fractional::<i32>::fixed(*v, *d);
+
+            assert_eq!(f.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            let v = f.write_str(&mut buff).unwrap();
+
+            assert_eq!(&v, s, "encoding mismatch for value: {}", s);
+        }
+    }
+
+    #[test]
+    fn fractional_rounded() {
+        let tests = &[
+            (1994, 1000, 2, "1.99"),
+            (1995, 1000, 2, "2.00"),
+            (1996, 1000, 2, "2.00"),
+            (-1994, 1000, 2, "-1.99"),
+            (-1995, 1000, 2, "-2.00"),
+            (-1996, 1000, 2, "-2.00"),
+            (99, 100, 1, "1.0"),
+            (5, 1000, 2, "0.01"),
+            (-5, 1000, 2, "-0.01"),
+            (4, 1000, 2, "0.00"),
+            (1, 3, 2, "0.33"),
+            (2, 3, 2, "0.67"),
+            (-2, 3, 2, "-0.67"),
+            (123, 10, 0, "12"),
+            (125, 10, 0, "13"),
+            (-125, 10, 0, "-13"),
+            (12, 10, 3, "1.200"),
+        ];
+
+        for (v, d, p, s) in tests {
+            let f = Fractional::<i32>::rounded(*v, *d, *p).unwrap();
+
+            assert_eq!(f.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(f.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn fractional_rounded_overflow() {
+        assert_eq!(Fractional::<i8>::rounded(100, 10, 3).err(), Some(Error::Overflow));
+        assert_eq!(Fractional::<i8>::rounded(1, 0, 1).err(), Some(Error::Overflow));
+    }
+
+    #[test]
+    fn fractional_with_decimals() {
+        let tests: &[(i32, u32, &str)] = &[
+            (1234, 0, "1234"),
+            (-7, 0, "-7"),
+            (1234, 2, "12.34"),
+            (-5, 2, "-0.05"),
+            (1200, 2, "12.00"),
+            (1234567, 6, "1.234567"),
+            (1234, 3, "1.234"),
+        ];
+
+        for (v, d, s) in tests {
+            let f = Fractional::with_decimals(*v, *d).unwrap();
+
+            assert_eq!(f.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(f.write_str(&mut buff), Ok(*s));
+        }
+
+        assert_eq!(Fractional::<i8>::with_decimals(1, 2).map(|f| f.divisor), Ok(100));
+        assert_eq!(Fractional::<i8>::with_decimals(1, 3).err(), Some(Error::Overflow));
+        assert_eq!(Fractional::<i64>::with_decimals(1, 40).err(), Some(Error::Overflow));
+    }
+
+    #[test]
+    fn fractional_split() {
+        let tests: &[(i32, i32, bool)] = &[
+            (1234056, 1_000, false),
+            (-1205, 100, false),
+            (-5, 100, false),
+            (1200, 1_000, true),
+            (7, 1, false),
+            (1, 3, false),
+        ];
+
+        for (v, d, fixed) in tests {
+            let f = Fractional{ value: *v, divisor: *d, fixed: *fixed };
+
+            let mut a = [0u8; 32];
+            let s = f.write_str(&mut a).unwrap();
+
+            let mut b = [0u8; 32];
+            let n = f.decimal_digits(&mut b).unwrap();
+            let digits = core::str::from_utf8(&b[..n]).unwrap();
+
+            // Rebuild from components
+            let mut c = [0u8; 32];
+            let i = match (f.is_negative(), f.integer_part()) {
+                (true, 0) => crate::write!(c, '-', 0i32),
+                (_, i) => crate::write!(c, i),
+            }.unwrap();
+            let m = match n {
+                0 => i,
+                _ => i + crate::write!(c[i..], '.', digits).unwrap(),
+            };
+
+            assert_eq!(core::str::from_utf8(&c[..m]).unwrap(), s);
+        }
+
+        let f = Fractional::new(-1205i32, 100);
+        assert_eq!(f.integer_part(), -12);
+        assert!(f.is_negative());
+
+        let mut b = [0u8; 1];
+        assert_eq!(f.decimal_digits(&mut b), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn fractional_format() {
+        let narrow = NumberFormat::new(",", "\u{202f}", 3);
+
+        let tests = &[
+            (Fractional::new(123456i32, 100).with_format(NumberFormat::DEFAULT), "1,234.56"),
+            (Fractional::new(123456i32, 100).with_format(NumberFormat::EUROPEAN), "1.234,56"),
+            (Fractional::new(-123456i32, 100).with_format(NumberFormat::EUROPEAN), "-1.234,56"),
+            (Fractional::new(-5i32, 100).with_format(NumberFormat::EUROPEAN), "-0,05"),
+            (Fractional::new(1234000i32, 1000).with_format(NumberFormat::EUROPEAN), "1.234"),
+            (Fractional::fixed(1234000i32, 1000).with_format(NumberFormat::EUROPEAN), "1.234,000"),
+            (Fractional::new(123456789i32, 100).with_format(narrow), "1\u{202f}234\u{202f}567,89"),
+            (Fractional::new(123456i32, 100).with_format(NumberFormat::new("·", "", 0)), "1234·56"),
+        ];
+
+        for (f, s) in tests {
+            assert_eq!(f.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(f.write_str(&mut buff), Ok(*s));
+        }
+    }
+
     fn encode_frac<N: Number>(tests: &[(N, N, &'static str)]) {
         for (v, d, s) in tests {
             println!("test v: {} d: {} s: {}", v, d, s);
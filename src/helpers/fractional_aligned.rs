@@ -0,0 +1,146 @@
+//! [FractionalAligned] helper for aligning decimal points across rows
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Fractional, FractionalAligned}};
+//! # let mut a = [0u8; 32];
+//! # let mut b = [0u8; 32];
+//! 
+//! let p = FractionalAligned::new(Fractional::new(125i32, 10), 4, 2);
+//! let n = FractionalAligned::new(Fractional::new(-325i32, 100), 4, 2);
+//! 
+//! assert_eq!(p.write_str(&mut a).unwrap(), "  12.50");
+//! assert_eq!(n.write_str(&mut b).unwrap(), "  -3.25");
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::{Fractional, fractional::Number};
+
+/// Scratch length for decimal digits, sufficient for the maximum
+/// number of decimal places emitted by [Fractional]
+const SCRATCH_LEN: usize = 18;
+
+/// Helper for encoding [Fractional] values with the decimal point at a fixed
+/// column, with the integer part (and any -ve sign) right-aligned to `int_width`
+/// with spaces and the decimal part left-aligned to `dec_width` with zeroes
+/// (or spaces, see [FractionalAligned::space_fill]).
+/// 
+/// Values exceeding the provided widths extend the column.
+pub struct FractionalAligned<N: Number> {
+    value: Fractional<N>,
+    int_width: usize,
+    dec_width: usize,
+    fill: char,
+}
+
+impl <N: Number> FractionalAligned<N> {
+    /// Create a new aligned fractional with the provided integer and decimal widths
+    pub const fn new(value: Fractional<N>, int_width: usize, dec_width: usize) -> Self {
+        Self{
+            value,
+            int_width,
+            dec_width,
+            fill: '0',
+        }
+    }
+
+    /// Pad the decimal part with spaces rather than zeroes
+    pub const fn space_fill(mut self) -> Self {
+        self.fill = ' ';
+        self
+    }
+
+    /// Check whether a -ve sign is required that won't be written by the integer part
+    fn negative_zero(&self) -> bool {
+        self.value.is_negative() && self.value.integer_part().is_zero()
+    }
+
+    /// Length of the integer part including any -ve sign
+    fn int_len(&self) -> usize {
+        self.value.integer_part().len() + self.negative_zero() as usize
+    }
+
+    /// Encode decimal digits to scratch, returning the number of digits
+    fn decimals(&self, scratch: &mut [u8; SCRATCH_LEN]) -> usize {
+        self.value.decimal_digits(scratch).unwrap_or(0)
+    }
+}
+
+/// [EncodeStr] implementation for [FractionalAligned]
+impl <N: Number> EncodeStr for FractionalAligned<N> {
+    fn len(&self) -> usize {
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let d = self.decimals(&mut scratch);
+
+        self.int_width.max(self.int_len()) + 1 + self.dec_width.max(d)
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let d = self.decimals(&mut scratch);
+
+        // Write right-aligned integer part
+        let mut n = 0;
+        for _i in self.int_len()..self.int_width {
+            n += ' '.write(&mut buff[n..])?;
+        }
+        if self.negative_zero() {
+            n += '-'.write(&mut buff[n..])?;
+        }
+        n += self.value.integer_part().write(&mut buff[n..])?;
+
+        // Write left-aligned decimal part
+        n += '.'.write(&mut buff[n..])?;
+        buff[n..][..d].copy_from_slice(&scratch[..d]);
+        n += d;
+        for _i in d..self.dec_width {
+            n += self.fill.write(&mut buff[n..])?;
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_fractional_aligned() {
+        let tests = &[
+            (FractionalAligned::new(Fractional::new(125i32, 10), 4, 2), "  12.50"),
+            (FractionalAligned::new(Fractional::new(-325i32, 100), 4, 2), "  -3.25"),
+            (FractionalAligned::new(Fractional::new(-5i32, 100), 4, 2), "  -0.05"),
+            (FractionalAligned::new(Fractional::new(1200i32, 100), 4, 2), "  12.00"),
+            (FractionalAligned::new(Fractional::new(1200i32, 100), 4, 2).space_fill(), "  12.  "),
+            (FractionalAligned::new(Fractional::new(123456i32, 10), 4, 2), "12345.60"),
+            (FractionalAligned::new(Fractional::new(1234i32, 1000), 2, 2), " 1.234"),
+        ];
+
+        for (f, s) in tests {
+            assert_eq!(f.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(f.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn fractional_aligned_columns() {
+        let values = [(125i32, 10), (-325, 100), (9999, 1), (-1, 1000), (0, 1)];
+
+        for (v, d) in values {
+            let f = FractionalAligned::new(Fractional::new(v, d), 5, 3);
+
+            let mut buff = [0u8; 32];
+            let s = f.write_str(&mut buff).unwrap();
+
+            assert_eq!(f.len(), 5 + 1 + 3);
+            assert_eq!(s.find('.'), Some(5), "misaligned value: {}", s);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! [Grouped] helper for displaying integers with digit group separators
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Grouped};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let g = Grouped::new(1234567u32);
+//! let s = g.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "1,234,567");
+//! ```
+
+use num_traits::PrimInt;
+
+use crate::{EncodeStr, Error};
+use super::NumberFormat;
+
+/// Scratch length for integer encoding, sufficient for [i128::MIN]
+const SCRATCH_LEN: usize = 40;
+
+/// Default number of digits per group
+const GROUP: usize = 3;
+
+/// Helper for encoding integers with separators between groups of digits,
+/// starting from the least significant digit
+pub struct Grouped<'a, N: EncodeStr + PrimInt> {
+    value: N,
+    sep: Separator<'a>,
+    group: usize,
+}
+
+/// Group separator
+enum Separator<'a> {
+    Char(char),
+    Str(&'a str),
+}
+
+/// [EncodeStr] implementation for [Separator]
+impl <'a> EncodeStr for Separator<'a> {
+    fn len(&self) -> usize {
+        match self {
+            Separator::Char(c) => c.len(),
+            Separator::Str(s) => s.len(),
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        match self {
+            Separator::Char(c) => c.write(buff),
+            Separator::Str(s) => s.write(buff),
+        }
+    }
+}
+
+impl <N: EncodeStr + PrimInt> Grouped<'static, N> {
+    /// Create a new grouped integer using `,` as the separator
+    pub const fn new(value: N) -> Self {
+        Self::with_sep(value, ',')
+    }
+
+    /// Create a new grouped integer using the provided separator
+    pub const fn with_sep(value: N, sep: char) -> Self {
+        Self{
+            value,
+            sep: Separator::Char(sep),
+            group: GROUP,
+        }
+    }
+}
+
+impl <'a, N: EncodeStr + PrimInt> Grouped<'a, N> {
+    /// Create a new grouped integer using the provided (potentially multi-byte)
+    /// separator, for example a narrow no-break space (`"\u{202f}"`)
+    pub const fn with_sep_str(value: N, sep: &'a str) -> Self {
+        Self{
+            value,
+            sep: Separator::Str(sep),
+            group: GROUP,
+        }
+    }
+
+    /// Apply the group separator and size from the provided [NumberFormat]
+    pub const fn format(mut self, format: NumberFormat<'a>) -> Self {
+        self.sep = Separator::Str(format.group);
+        self.group = format.group_size;
+        self
+    }
+
+    /// Set the number of digits per group (defaults to 3), a group size
+    /// of zero disables grouping
+    pub const fn group(mut self, group: usize) -> Self {
+        self.group = group;
+        self
+    }
+
+    /// Number of digits (excluding any -ve sign)
+    fn digits(&self) -> usize {
+        match self.value < N::zero() {
+            true => self.value.len() - 1,
+            false => self.value.len(),
+        }
+    }
+
+    /// Number of separators to be inserted
+    fn separators(&self) -> usize {
+        match self.group {
+            0 => 0,
+            g => (self.digits() - 1) / g,
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [Grouped]
+impl <'a, N: EncodeStr + PrimInt> EncodeStr for Grouped<'a, N> {
+    fn len(&self) -> usize {
+        self.value.len() + self.separators() * self.sep.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Encode value to scratch buffer
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let s = self.value.write(&mut scratch)?;
+
+        // Copy -ve sign
+        let sign = s - self.digits();
+        buff[..sign].copy_from_slice(&scratch[..sign]);
+        let mut n = sign;
+
+        // Copy digits, inserting separators between groups
+        let digits = self.digits();
+        for (i, c) in scratch[sign..s].iter().enumerate() {
+            if i > 0 && self.group > 0 && (digits - i) % self.group == 0 {
+                n += self.sep.write(&mut buff[n..])?;
+            }
+
+            buff[n] = *c;
+            n += 1;
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::{Grouped, NumberFormat};
+
+    #[test]
+    fn encode_grouped() {
+        let tests = &[
+            (Grouped::new(0i32), "0"),
+            (Grouped::new(100i32), "100"),
+            (Grouped::new(1000i32), "1,000"),
+            (Grouped::new(1234567i32), "1,234,567"),
+            (Grouped::new(-1234567i32), "-1,234,567"),
+            (Grouped::new(-123i32), "-123"),
+            (Grouped::new(-1000i32), "-1,000"),
+        ];
+
+        for (g, s) in tests {
+            assert_eq!(g.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(g.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_grouped_config() {
+        let tests = &[
+            (Grouped::new(u64::MAX), "18,446,744,073,709,551,615"),
+            (Grouped::with_sep(1234567u64, '.'), "1.234.567"),
+            (Grouped::with_sep(12345678u64, ' ').group(4), "1234 5678"),
+            (Grouped::new(123456789u64).group(2), "1,23,45,67,89"),
+            (Grouped::new(1234567u64).group(0), "1234567"),
+        ];
+
+        for (g, s) in tests {
+            assert_eq!(g.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(g.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_grouped_multibyte_sep() {
+        let g = Grouped::with_sep_str(1234567u32, "\u{202f}");
+
+        // 7 digits + 2 * 3 byte separators
+        assert_eq!(g.len(), 13);
+
+        let mut buff = [0u8; 32];
+        assert_eq!(g.write_str(&mut buff), Ok("1\u{202f}234\u{202f}567"));
+    }
+
+    #[test]
+    fn encode_grouped_format() {
+        let tests = &[
+            (Grouped::new(1234567i32).format(NumberFormat::DEFAULT), "1,234,567"),
+            (Grouped::new(1234567i32).format(NumberFormat::EUROPEAN), "1.234.567"),
+            (Grouped::new(-1234567i32).format(NumberFormat::new(",", "\u{202f}", 3)), "-1\u{202f}234\u{202f}567"),
+            (Grouped::new(12345678i32).format(NumberFormat::new(".", "'", 4)), "1234'5678"),
+        ];
+
+        for (g, s) in tests {
+            assert_eq!(g.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(g.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
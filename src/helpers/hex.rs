@@ -1,23 +1,43 @@
-//! [Hex] helper for displaying arrays as hex
+//! [Hex] helper for displaying arrays as hex, and [HexInt] / [HexBytes] for integer values
 //! 
 //! ```
-//! # use emstr::{EncodeStr, helpers::Hex};
+//! # use emstr::{EncodeStr, helpers::{Hex, HexInt}};
 //! # let mut buff = [0u8; 32];
 //! 
 //! let h = Hex(&[0x12, 0x34, 0xff]);
 //! let s = h.write_str(&mut buff).unwrap();
 //! 
 //! assert_eq!(s, "1234ff");
+//! 
+//! let h = HexInt::new(0xDEADu32);
+//! let s = h.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "dead");
 //! ```
 //! 
 
-use crate::{EncodeStr, Error};
+use core::mem::size_of;
+
+use num_traits::PrimInt;
 
-/// Wrapper type for encoding byte arrays as hex strings
+use crate::{EncodeStr, Error, WriteMode};
+
+/// Wrapper type for encoding byte arrays as hex strings, use references
+/// (`Hex(&buff)`) to avoid copying large arrays
 pub struct Hex<B: AsRef<[u8]>>(pub B);
 
+impl <B: AsRef<[u8]>> Hex<B> {
+    /// Create a new hex wrapper, usable in `const` and `static` contexts
+    pub const fn new(b: B) -> Self {
+        Self(b)
+    }
+}
+
 /// Value to character mapping
-const HEX_MAP: [char; 16] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f'];
+pub(crate) const HEX_MAP: [char; 16] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f'];
+
+/// Value to uppercase character mapping
+pub(crate) const HEX_MAP_UPPER: [char; 16] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F'];
 
 /// [EncodeStr] implementation to write bytes as hex
 impl <B: AsRef<[u8]>> EncodeStr for Hex<B> {
@@ -43,11 +63,378 @@ impl <B: AsRef<[u8]>> EncodeStr for Hex<B> {
 
         Ok(b.len() * 2)
     }
+
+    unsafe fn write_unchecked(&self, buff: &mut [u8]) -> usize {
+        let b = self.0.as_ref();
+        debug_assert!(buff.len() >= b.len() * 2);
+
+        // Write out hex
+        for (i, v) in b.iter().enumerate() {
+            // SAFETY: the caller guarantees `buff` is at least `len()` bytes
+            unsafe {
+                *buff.get_unchecked_mut(i * 2) = HEX_MAP[(v >> 4) as usize] as u8;
+                *buff.get_unchecked_mut(i * 2 + 1) = HEX_MAP[(v & 0x0F) as usize] as u8;
+            }
+        }
+
+        b.len() * 2
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        if mode == WriteMode::Strict || self.len() <= buff.len() {
+            return self.write(buff);
+        }
+
+        crate::write_chunks_truncated(self, buff)
+    }
+
+    fn write_chunks(&self, scratch: &mut [u8], mut f: impl FnMut(&str) -> Result<(), Error>) -> Result<(), Error> {
+        // Each input byte requires two characters
+        let c = scratch.len() / 2;
+        if c == 0 && !self.0.as_ref().is_empty() {
+            return Err(Error::BufferLength)
+        }
+
+        for chunk in self.0.as_ref().chunks(c.max(1)) {
+            f(Hex(chunk).write_str(scratch)?)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Wrapper type for encoding byte arrays as uppercase hex strings
+pub struct HexUpper<B: AsRef<[u8]>>(pub B);
+
+/// [EncodeStr] implementation to write bytes as uppercase hex
+impl <B: AsRef<[u8]>> EncodeStr for HexUpper<B> {
+    fn len(&self) -> usize {
+        self.0.as_ref().len() * 2
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write out hex
+        for (i, v) in self.0.as_ref().iter().enumerate() {
+            buff[i * 2] = HEX_MAP_UPPER[(v >> 4) as usize] as u8;
+            buff[i * 2 + 1] = HEX_MAP_UPPER[(v & 0x0F) as usize] as u8;
+        }
+
+        Ok(self.len())
+    }
+}
+
+/// Extension trait for encoding byte slices as hex in `write!` chains
+/// 
+/// ```
+/// # use emstr::helpers::HexExt;
+/// let id = [0xde, 0xad];
+/// let mut buff = [0u8; 16];
+/// 
+/// let n = emstr::write!(buff, "id=", id.hex()).unwrap();
+/// assert_eq!(&buff[..n], b"id=dead");
+/// ```
+pub trait HexExt {
+    /// Encode as lowercase hex, see [Hex]
+    fn hex(&self) -> Hex<&[u8]>;
+
+    /// Encode as uppercase hex, see [HexUpper]
+    fn hex_upper(&self) -> HexUpper<&[u8]>;
+}
+
+/// [HexExt] implementation for byte slice types
+impl <T: AsRef<[u8]> + ?Sized> HexExt for T {
+    fn hex(&self) -> Hex<&[u8]> {
+        Hex(self.as_ref())
+    }
+
+    fn hex_upper(&self) -> HexUpper<&[u8]> {
+        HexUpper(self.as_ref())
+    }
+}
+
+/// Helper for encoding byte arrays as `0x` prefixed hex strings,
+/// uppercase by default (`0xDEADBEEF`) or lowercase with [Hex0x::lower]
+pub struct Hex0x<B: AsRef<[u8]>> {
+    data: B,
+    upper: bool,
+}
+
+impl <B: AsRef<[u8]>> Hex0x<B> {
+    /// Create a new uppercase `0x` prefixed hex wrapper
+    pub const fn new(data: B) -> Self {
+        Self{
+            data,
+            upper: true,
+        }
+    }
+
+    /// Use lowercase hex characters
+    pub const fn lower(mut self) -> Self {
+        self.upper = false;
+        self
+    }
+}
+
+/// [EncodeStr] implementation to write bytes as `0x` prefixed hex
+impl <B: AsRef<[u8]>> EncodeStr for Hex0x<B> {
+    fn len(&self) -> usize {
+        2 + self.data.as_ref().len() * 2
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let map = match self.upper {
+            true => &HEX_MAP_UPPER,
+            false => &HEX_MAP,
+        };
+
+        // Write prefix then hex
+        let n = "0x".write(buff)?;
+        for (i, v) in self.data.as_ref().iter().enumerate() {
+            buff[n + i * 2] = map[(v >> 4) as usize] as u8;
+            buff[n + i * 2 + 1] = map[(v & 0x0F) as usize] as u8;
+        }
+
+        Ok(self.len())
+    }
+}
+
+/// Fetch the two's complement bits of an integer at the width of the type
+fn twos_complement<T: PrimInt>(v: T) -> u128 {
+    let bits = size_of::<T>() * 8;
+
+    match v.to_u128() {
+        Some(v) => v,
+        None => v.to_i128().unwrap_or(0) as u128 & (u128::MAX >> (128 - bits)),
+    }
+}
+
+/// Helper for encoding integer values as hex strings, either
+/// minimal (`0xf00u16` -> `f00`) or fixed to the type width (`0f00`).
+/// 
+/// Signed values are encoded as two's complement at the width of the type,
+/// so negative values always use the full width (`-1i16` -> `ffff`)
+pub struct HexInt<T: PrimInt> {
+    value: T,
+    fixed: bool,
+}
+
+impl <T: PrimInt> HexInt<T> {
+    /// Create a new minimal width hex integer
+    pub const fn new(value: T) -> Self {
+        Self{
+            value,
+            fixed: false,
+        }
+    }
+
+    /// Create a new hex integer zero-padded to the nibble count of the type
+    pub const fn fixed(value: T) -> Self {
+        Self{
+            value,
+            fixed: true,
+        }
+    }
+}
+
+/// [EncodeStr] implementation to write integers as hex
+impl <T: PrimInt> EncodeStr for HexInt<T> {
+    fn len(&self) -> usize {
+        let bits = size_of::<T>() * 8;
+
+        match self.fixed {
+            true => bits / 4,
+            false => (bits - self.value.leading_zeros() as usize).div_ceil(4).max(1),
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let n = self.len();
+        let v = twos_complement(self.value);
+
+        // Check buffer length
+        if buff.len() < n {
+            return Err(Error::BufferLength)
+        }
+
+        // Write out nibbles, most significant first
+        for (i, b) in buff[..n].iter_mut().enumerate() {
+            *b = HEX_MAP[((v >> ((n - i - 1) * 4)) & 0x0F) as usize] as u8;
+        }
+
+        Ok(n)
+    }
+}
+
+/// Byte order selector for [HexBytes]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endian {
+    /// Most significant byte first
+    Big,
+    /// Least significant byte first
+    Little,
+}
+
+/// Helper for encoding the (two's complement) bytes of an integer as hex in a chosen
+/// byte order (`0x12345678u32` -> `12345678` (BE) or `78563412` (LE)),
+/// always emitting `2 * size_of::<T>()` characters
+pub struct HexBytes<T: PrimInt> {
+    value: T,
+    endian: Endian,
+}
+
+impl <T: PrimInt> HexBytes<T> {
+    /// Create a new hex bytes wrapper with the provided byte order
+    pub const fn new(value: T, endian: Endian) -> Self {
+        Self{
+            value,
+            endian,
+        }
+    }
+
+    /// Create a new hex bytes wrapper in big-endian (network) byte order
+    pub const fn be(value: T) -> Self {
+        Self::new(value, Endian::Big)
+    }
+
+    /// Create a new hex bytes wrapper in little-endian byte order
+    pub const fn le(value: T) -> Self {
+        Self::new(value, Endian::Little)
+    }
+}
+
+/// [EncodeStr] implementation to write integer bytes as hex
+impl <T: PrimInt> EncodeStr for HexBytes<T> {
+    fn len(&self) -> usize {
+        size_of::<T>() * 2
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let n = size_of::<T>();
+        let v = twos_complement(self.value);
+
+        // Check buffer length
+        if buff.len() < n * 2 {
+            return Err(Error::BufferLength)
+        }
+
+        // Write out bytes in the selected order
+        for i in 0..n {
+            let shift = match self.endian {
+                Endian::Big => (n - i - 1) * 8,
+                Endian::Little => i * 8,
+            };
+            let b = (v >> shift) as u8;
+
+            buff[i * 2] = HEX_MAP[(b >> 4) as usize] as u8;
+            buff[i * 2 + 1] = HEX_MAP[(b & 0x0F) as usize] as u8;
+        }
+
+        Ok(n * 2)
+    }
+}
+
+/// Resumable hex encoder for writing large byte arrays across multiple
+/// (potentially small) buffers, continuing from the previous offset on each write
+pub struct HexEncoder<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl <'a> HexEncoder<'a> {
+    /// Create a new resumable hex encoder over the provided data
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self{
+            data,
+            offset: 0,
+        }
+    }
+
+    /// Write the next portion of hex to the buffer, returning the number
+    /// of characters written and whether encoding is complete
+    pub fn write(&mut self, buff: &mut [u8]) -> (usize, bool) {
+        let n = self.remaining().min(buff.len());
+
+        for (i, b) in buff[..n].iter_mut().enumerate() {
+            let c = self.offset + i;
+            let v = self.data[c / 2] as usize;
+
+            *b = match c % 2 {
+                0 => HEX_MAP[(v >> 4) & 0x0F],
+                _ => HEX_MAP[v & 0x0F],
+            } as u8;
+        }
+
+        self.offset += n;
+
+        (n, self.is_complete())
+    }
+
+    /// Fetch the number of characters remaining to be written
+    pub const fn remaining(&self) -> usize {
+        self.data.len() * 2 - self.offset
+    }
+
+    /// Check whether encoding is complete
+    pub const fn is_complete(&self) -> bool {
+        self.remaining() == 0
+    }
+}
+
+/// Decode a hex string (upper or lowercase) into the provided buffer,
+/// returning the number of bytes written
+/// 
+/// ```
+/// # use emstr::helpers::decode_hex;
+/// let mut out = [0u8; 4];
+/// let n = decode_hex("01aBcD", &mut out).unwrap();
+/// 
+/// assert_eq!(&out[..n], &[0x01, 0xab, 0xcd]);
+/// ```
+/// 
+/// Returns [Error::InvalidInput] for odd length strings or non-hex characters,
+/// or [Error::BufferLength] where `out` is shorter than `s.len() / 2`.
+pub fn decode_hex(s: &str, out: &mut [u8]) -> Result<usize, Error> {
+    let s = s.as_bytes();
+
+    // Check input and buffer lengths
+    if s.len() % 2 != 0 {
+        return Err(Error::InvalidInput)
+    }
+    let n = s.len() / 2;
+    if out.len() < n {
+        return Err(Error::BufferLength)
+    }
+
+    for (o, c) in out.iter_mut().zip(s.chunks_exact(2)) {
+        *o = (nibble(c[0])? << 4) | nibble(c[1])?;
+    }
+
+    Ok(n)
+}
+
+/// Decode a single hex character
+fn nibble(c: u8) -> Result<u8, Error> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(Error::InvalidInput),
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Hex, EncodeStr, HEX_MAP};
+    use super::{decode_hex, Hex, Hex0x, HexUpper, HexExt, HexInt, HexBytes, Endian, HexEncoder, EncodeStr, HEX_MAP};
 
     #[test]
     fn encode_hex() {
@@ -59,11 +446,262 @@ mod test {
         assert_eq!(v, "00123456789abcde");
     }
 
+    #[test]
+    fn encode_hex_refs() {
+        let data = [0x01, 0x23, 0x45, 0x67];
+        let mut buff = [0u8; 32];
+
+        // Array reference
+        let h: Hex<&[u8; 4]> = Hex(&data);
+        assert_eq!(h.write_str(&mut buff), Ok("01234567"));
+
+        // Slice reference
+        let h: Hex<&[u8]> = Hex(&data[1..]);
+        assert_eq!(h.write_str(&mut buff), Ok("234567"));
+    }
+
+    #[test]
+    fn encode_hex_const() {
+        const H: Hex<&[u8]> = Hex::new(&[0x01]);
+        static S: Hex<[u8; 2]> = Hex::new([0xab, 0xcd]);
+
+        let mut buff = [0u8; 32];
+        assert_eq!(H.write_str(&mut buff), Ok("01"));
+        assert_eq!(S.write_str(&mut buff), Ok("abcd"));
+    }
+
+    #[test]
+    fn encode_hex_ext() {
+        let data = [0x01, 0xab, 0xcd, 0xef];
+        let (mut a, mut b) = ([0u8; 32], [0u8; 32]);
+
+        assert_eq!(data.hex().write_str(&mut a), Hex(&data).write_str(&mut b));
+        assert_eq!(data.hex_upper().write_str(&mut a), HexUpper(&data).write_str(&mut b));
+        assert_eq!(data.hex_upper().write_str(&mut a), Ok("01ABCDEF"));
+
+        let v: &[u8] = &data[2..];
+        assert_eq!(v.hex().write_str(&mut a), Ok("cdef"));
+
+        let s = crate::write_str!(a, "id=", data.hex(), '/', "hi".hex()).unwrap();
+        assert_eq!(s, "id=01abcdef/6869");
+    }
+
+    #[test]
+    fn encode_hex_0x() {
+        let data = [0xde, 0xad, 0xbe, 0xef];
+        let mut buff = [0u8; 32];
+
+        let h = Hex0x::new(&data);
+        assert_eq!(h.len(), 10);
+        assert_eq!(h.write_str(&mut buff), Ok("0xDEADBEEF"));
+
+        let h = Hex0x::new(&data).lower();
+        assert_eq!(h.write_str(&mut buff), Ok("0xdeadbeef"));
+
+        let h = Hex0x::new(&[]);
+        assert_eq!(h.len(), 2);
+        assert_eq!(h.write_str(&mut buff), Ok("0x"));
+
+        let s = crate::write_str!(buff, "addr: ", Hex0x::new(&[0x01, 0xab]), " ok").unwrap();
+        assert_eq!(s, "addr: 0x01AB ok");
+    }
+
+    #[test]
+    fn encode_hex_chunks() {
+        let data = [0xa5u8; 1000];
+        let h = Hex(&data);
+
+        let mut scratch = [0u8; 64];
+        let (mut n, mut chunks) = (0, 0);
+
+        h.write_chunks(&mut scratch, |s| {
+            assert!(s.chars().all(|c| c == 'a' || c == '5'));
+            n += s.len();
+            chunks += 1;
+            Ok(())
+        }).unwrap();
+
+        assert_eq!(n, h.len());
+        assert_eq!(chunks, 32);
+
+        // Single chunk default for small types
+        let mut out = [0u8; 16];
+        1234u32.write_chunks(&mut scratch, |s| {
+            out[..s.len()].copy_from_slice(s.as_bytes());
+            Ok(())
+        }).unwrap();
+        assert_eq!(&out[..4], b"1234");
+
+        // Scratch too small for a single byte
+        assert_eq!(h.write_chunks(&mut [0u8; 1], |_| Ok(())), Err(crate::Error::BufferLength));
+    }
+
+    #[test]
+    fn encode_hex_int() {
+        let mut buff = [0u8; 32];
+
+        assert_eq!(HexInt::new(0u8).write_str(&mut buff), Ok("0"));
+        assert_eq!(HexInt::new(0xau8).write_str(&mut buff), Ok("a"));
+        assert_eq!(HexInt::new(0xdeadu16).write_str(&mut buff), Ok("dead"));
+        assert_eq!(HexInt::new(0xf00u16).write_str(&mut buff), Ok("f00"));
+        assert_eq!(HexInt::new(0x12345u32).write_str(&mut buff), Ok("12345"));
+        assert_eq!(HexInt::new(u64::MAX).write_str(&mut buff), Ok("ffffffffffffffff"));
+
+        assert_eq!(HexInt::fixed(0u8).write_str(&mut buff), Ok("00"));
+        assert_eq!(HexInt::fixed(0xau8).write_str(&mut buff), Ok("0a"));
+        assert_eq!(HexInt::fixed(0xf00u16).write_str(&mut buff), Ok("0f00"));
+        assert_eq!(HexInt::fixed(0x12345u32).write_str(&mut buff), Ok("00012345"));
+        assert_eq!(HexInt::fixed(0u32).write_str(&mut buff), Ok("00000000"));
+    }
+
+    #[test]
+    fn encode_hex_signed() {
+        let mut buff = [0u8; 40];
+
+        assert_eq!(HexInt::new(-1i8).write_str(&mut buff), Ok("ff"));
+        assert_eq!(HexInt::new(-1i16).write_str(&mut buff), Ok("ffff"));
+        assert_eq!(HexInt::new(i16::MIN).write_str(&mut buff), Ok("8000"));
+        assert_eq!(HexInt::new(-2i32).write_str(&mut buff), Ok("fffffffe"));
+        assert_eq!(HexInt::new(-1i128).write_str(&mut buff), Ok("ffffffffffffffffffffffffffffffff"));
+        assert_eq!(HexInt::new(0x1234i16).write_str(&mut buff), Ok("1234"));
+        assert_eq!(HexInt::new(0x12i16).write_str(&mut buff), Ok("12"));
+        assert_eq!(HexInt::fixed(0x12i16).write_str(&mut buff), Ok("0012"));
+        assert_eq!(HexInt::new(i16::MAX).write_str(&mut buff), Ok("7fff"));
+
+        assert_eq!(HexInt::new(-1i16).len(), 4);
+        assert_eq!(HexInt::new(i16::MIN).len(), 4);
+        assert_eq!(HexInt::fixed(1i16).len(), 4);
+
+        assert_eq!(HexBytes::be(-1i16).write_str(&mut buff), Ok("ffff"));
+        assert_eq!(HexBytes::be(i16::MIN).write_str(&mut buff), Ok("8000"));
+        assert_eq!(HexBytes::le(i16::MIN).write_str(&mut buff), Ok("0080"));
+        assert_eq!(HexBytes::be(0x1234i16).write_str(&mut buff), Ok("1234"));
+        assert_eq!(HexBytes::le(-2i32).write_str(&mut buff), Ok("feffffff"));
+        assert_eq!(HexBytes::le(-2i32).len(), 8);
+
+        // Matches the byte semantics of Hex
+        let (mut a, mut b) = ([0u8; 32], [0u8; 32]);
+        assert_eq!(HexBytes::be(-12345i32).write_str(&mut a), Hex((-12345i32).to_be_bytes()).write_str(&mut b));
+    }
+
+    #[test]
+    fn decode_hex_round_trip() {
+        let mut buff = [0u8; 64];
+        let mut out = [0u8; 32];
+
+        let tests: &[&[u8]] = &[
+            &[],
+            &[0x00],
+            &[0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef],
+            &[0xff; 32],
+        ];
+
+        for &data in tests {
+            let s = Hex(data).write_str(&mut buff).unwrap();
+            assert_eq!(decode_hex(s, &mut out), Ok(data.len()));
+            assert_eq!(&out[..data.len()], data);
+
+            let s = HexUpper(data).write_str(&mut buff).unwrap();
+            assert_eq!(decode_hex(s, &mut out), Ok(data.len()));
+            assert_eq!(&out[..data.len()], data);
+        }
+
+        let n = decode_hex("DeAdBeEf", &mut out).unwrap();
+        assert_eq!(&out[..n], &[0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn decode_hex_errors() {
+        use crate::Error;
+
+        let mut out = [0u8; 4];
+
+        assert_eq!(decode_hex("abc", &mut out), Err(Error::InvalidInput));
+        assert_eq!(decode_hex("0g", &mut out), Err(Error::InvalidInput));
+        assert_eq!(decode_hex("0x12", &mut out), Err(Error::InvalidInput));
+        assert_eq!(decode_hex("µµ", &mut out), Err(Error::InvalidInput));
+        assert_eq!(decode_hex("0102030405", &mut out), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn hex_int_len() {
+        for v in [0u32, 1, 0xf, 0x10, 0xfff, 0x1000, 0xdeadbeef] {
+            let s = format!("{:x}", v);
+            assert_eq!(HexInt::new(v).len(), s.len());
+            assert_eq!(HexInt::fixed(v).len(), 8);
+        }
+    }
+
+    #[test]
+    fn encode_hex_bytes() {
+        let mut buff = [0u8; 32];
+
+        let tests = &[
+            (HexBytes::be(0x1234u16), "1234"),
+            (HexBytes::le(0x1234u16), "3412"),
+            (HexBytes::be(0x000au16), "000a"),
+            (HexBytes::le(0x000au16), "0a00"),
+        ];
+
+        for (h, s) in tests {
+            assert_eq!(h.len(), 4);
+            assert_eq!(h.write_str(&mut buff), Ok(*s));
+        }
+
+        let tests = &[
+            (HexBytes::be(0x12345678u32), "12345678"),
+            (HexBytes::le(0x12345678u32), "78563412"),
+            (HexBytes::new(0xdeadu32, Endian::Big), "0000dead"),
+            (HexBytes::new(0xdeadu32, Endian::Little), "adde0000"),
+        ];
+
+        for (h, s) in tests {
+            assert_eq!(h.len(), 8);
+            assert_eq!(h.write_str(&mut buff), Ok(*s));
+        }
+
+        // Matches the byte semantics of Hex
+        let v = 0x0102030405060708u64;
+        let (mut a, mut b) = ([0u8; 32], [0u8; 32]);
+        assert_eq!(HexBytes::le(v).write_str(&mut a), Hex(v.to_le_bytes()).write_str(&mut b));
+        assert_eq!(HexBytes::be(v).write_str(&mut a), Hex(v.to_be_bytes()).write_str(&mut b));
+
+        assert_eq!(HexBytes::be(1u32).write(&mut buff[..7]), Err(crate::Error::BufferLength));
+    }
+
+    #[test]
+    fn hex_encoder_resume() {
+        let data = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99];
+        let mut e = HexEncoder::new(&data);
+
+        let mut out = [0u8; 20];
+        let mut n = 0;
+
+        loop {
+            let mut buff = [0u8; 4];
+            let (w, done) = e.write(&mut buff);
+
+            out[n..][..w].copy_from_slice(&buff[..w]);
+            n += w;
+
+            if done {
+                break;
+            }
+        }
+
+        let mut full = [0u8; 32];
+        assert_eq!(n, 20);
+        assert_eq!(&out[..n], Hex(&data).write_str(&mut full).unwrap().as_bytes());
+
+        // Further writes are a no-op
+        assert_eq!(e.write(&mut [0u8; 4]), (0, true));
+    }
+
     #[test]
     fn hex_map() {
-        for i in 0..HEX_MAP.len() {
+        for (i, c) in HEX_MAP.iter().enumerate() {
 
-            let a = HEX_MAP[i].to_string();
+            let a = c.to_string();
             let e = format!("{:x}", i);
 
             assert_eq!(&a, &e);
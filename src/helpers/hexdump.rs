@@ -0,0 +1,165 @@
+//! [HexDump] helper for displaying bytes as hexdump rows with offsets
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::HexDump};
+//! # let mut buff = [0u8; 64];
+//! 
+//! let h = HexDump::new(b"abc\x00\x01xyz", 4);
+//! let s = h.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "0000: 61 62 63 00  abc.\n0004: 01 78 79 7a  .xyz");
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::hex::HEX_MAP;
+
+/// Minimum number of offset digits
+const MIN_OFFSET_DIGITS: usize = 4;
+
+/// Helper for encoding bytes as hexdump rows, each with a hex offset column,
+/// space separated hex bytes, and an ASCII gutter (non-printable bytes as `.`).
+/// 
+/// Rows are separated by `\n` (with no trailing newline), the hex column of a
+/// partial final row is space padded so the ASCII gutter remains aligned.
+pub struct HexDump<'a> {
+    data: &'a [u8],
+    width: usize,
+}
+
+impl <'a> HexDump<'a> {
+    /// Create a new hexdump with the provided number of bytes per row
+    /// (a width of zero is treated as one)
+    pub const fn new(data: &'a [u8], width: usize) -> Self {
+        Self{
+            data,
+            width: if width == 0 { 1 } else { width },
+        }
+    }
+
+    /// Number of offset digits, sufficient for the final row offset
+    fn offset_digits(&self) -> usize {
+        let mut n = 1;
+        let mut v = self.data.len().saturating_sub(1) >> 4;
+        while v > 0 {
+            v >>= 4;
+            n += 1;
+        }
+
+        n.max(MIN_OFFSET_DIGITS)
+    }
+
+    /// Length of a row containing `count` bytes
+    fn row_len(&self, count: usize) -> usize {
+        self.offset_digits() + 2 + self.width * 3 - 1 + 2 + count
+    }
+}
+
+/// [EncodeStr] implementation for [HexDump]
+impl <'a> EncodeStr for HexDump<'a> {
+    fn len(&self) -> usize {
+        let rows = self.data.len().div_ceil(self.width);
+        let partial = self.data.len() % self.width;
+
+        let mut n = rows * self.row_len(self.width) + rows.saturating_sub(1);
+        if partial > 0 {
+            n -= self.width - partial;
+        }
+
+        n
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let digits = self.offset_digits();
+        let mut n = 0;
+
+        for (r, row) in self.data.chunks(self.width).enumerate() {
+            if r > 0 {
+                n += '\n'.write(&mut buff[n..])?;
+            }
+
+            // Write offset column
+            let offset = r * self.width;
+            for i in 0..digits {
+                buff[n + i] = HEX_MAP[(offset >> ((digits - i - 1) * 4)) & 0x0F] as u8;
+            }
+            n += digits;
+            n += ": ".write(&mut buff[n..])?;
+
+            // Write hex column, padding partial rows
+            for i in 0..self.width {
+                if i > 0 {
+                    n += ' '.write(&mut buff[n..])?;
+                }
+
+                match row.get(i) {
+                    Some(b) => {
+                        buff[n] = HEX_MAP[(b >> 4) as usize] as u8;
+                        buff[n + 1] = HEX_MAP[(b & 0x0F) as usize] as u8;
+                    },
+                    None => buff[n..][..2].copy_from_slice(b"  "),
+                }
+                n += 2;
+            }
+
+            // Write ASCII gutter
+            n += "  ".write(&mut buff[n..])?;
+            for b in row {
+                buff[n] = match b {
+                    0x20..=0x7e => *b,
+                    _ => b'.',
+                };
+                n += 1;
+            }
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_hexdump() {
+        let tests: &[(&[u8], usize, &str)] = &[
+            (&[], 4, ""),
+            (b"abcd", 4, "0000: 61 62 63 64  abcd"),
+            (&[0x12, 0x34, 0x56, 0x78], 4, "0000: 12 34 56 78  .4Vx"),
+            (b"abcdef", 4, "0000: 61 62 63 64  abcd\n0004: 65 66        ef"),
+            (&[0x00, 0x7f, 0x20, 0x7e, 0xff], 8, "0000: 00 7f 20 7e ff           .. ~."),
+            (b"ab", 1, "0000: 61  a\n0001: 62  b"),
+        ];
+
+        for (d, w, s) in tests {
+            let h = HexDump::new(d, *w);
+
+            assert_eq!(h.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 128];
+            assert_eq!(h.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn hexdump_offsets() {
+        let data = [0xa5u8; 0x10001];
+        let h = HexDump::new(&data, 16);
+
+        let mut buff = [0u8; 0x10001 * 5];
+        let s = h.write_str(&mut buff).unwrap();
+
+        // Offsets widen to fit the final row
+        assert!(s.starts_with("00000: a5"));
+        let last = s.lines().last().unwrap();
+        assert_eq!(last.len(), 5 + 2 + 16 * 3 - 1 + 2 + 1);
+        assert!(last.starts_with("10000: a5 "));
+        assert!(last.ends_with("   ."));
+        assert_eq!(h.len(), s.len());
+    }
+}
@@ -0,0 +1,200 @@
+//! [Join] and [JoinArray] helpers for displaying slices and arrays of encodable
+//! types with separators
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Join, JoinArray}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let j = Join::new(&[1u32, 2, 3], ", ");
+//! let s = j.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "1, 2, 3");
+//! 
+//! let j = JoinArray::new(&["a", "b"], "/");
+//! assert_eq!(j.write_str(&mut buff), Ok("a/b"));
+//! ```
+
+use crate::{EncodeStr, Error, WriteMode};
+
+/// Helper for encoding a slice of [EncodeStr] items with a separator between elements
+pub struct Join<'a, T: EncodeStr> {
+    items: &'a [T],
+    sep: &'a str,
+}
+
+impl <'a, T: EncodeStr> Join<'a, T> {
+    /// Create a new join helper over the provided items and separator
+    pub const fn new(items: &'a [T], sep: &'a str) -> Self {
+        Self{
+            items,
+            sep,
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [Join]
+impl <'a, T: EncodeStr> EncodeStr for Join<'a, T> {
+    fn len(&self) -> usize {
+        let n: usize = self.items.iter().map(|i| i.len()).sum();
+        n + self.items.len().saturating_sub(1) * self.sep.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let mut n = 0;
+
+        for (i, v) in self.items.iter().enumerate() {
+            // Write separator between elements
+            if i > 0 {
+                n += self.sep.write(&mut buff[n..])?;
+            }
+
+            n += v.write(&mut buff[n..])?;
+        }
+
+        Ok(n)
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        if mode == WriteMode::Strict || self.len() <= buff.len() {
+            return self.write(buff);
+        }
+
+        // Truncate elements and separators in order until the buffer is full
+        let mut n = 0;
+
+        for (i, v) in self.items.iter().enumerate() {
+            if i > 0 && !crate::write_part_truncated(&self.sep, buff, &mut n)? {
+                break;
+            }
+
+            if !crate::write_part_truncated(v, buff, &mut n)? {
+                break;
+            }
+        }
+
+        Ok(n)
+    }
+
+    fn write_chunks(&self, scratch: &mut [u8], mut f: impl FnMut(&str) -> Result<(), Error>) -> Result<(), Error> {
+        for (i, v) in self.items.iter().enumerate() {
+            if i > 0 {
+                f(self.sep)?;
+            }
+
+            v.write_chunks(scratch, &mut f)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.sep.validate()?;
+        self.items.iter().try_for_each(|i| i.validate())
+    }
+}
+
+/// Helper for encoding a fixed size array of [EncodeStr] items with a
+/// separator between elements, see [Join] for slices
+pub struct JoinArray<'a, T: EncodeStr, const N: usize> {
+    items: &'a [T; N],
+    sep: &'a str,
+}
+
+impl <'a, T: EncodeStr, const N: usize> JoinArray<'a, T, N> {
+    /// Create a new join helper over the provided array and separator
+    pub const fn new(items: &'a [T; N], sep: &'a str) -> Self {
+        Self{
+            items,
+            sep,
+        }
+    }
+
+    /// Fetch a [Join] over the array items
+    const fn join(&self) -> Join<'a, T> {
+        Join::new(self.items, self.sep)
+    }
+}
+
+/// [EncodeStr] implementation for [JoinArray]
+impl <'a, T: EncodeStr, const N: usize> EncodeStr for JoinArray<'a, T, N> {
+    fn len(&self) -> usize {
+        self.join().len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        self.join().write(buff)
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        self.join().write_with(buff, mode)
+    }
+
+    fn write_chunks(&self, scratch: &mut [u8], f: impl FnMut(&str) -> Result<(), Error>) -> Result<(), Error> {
+        self.join().write_chunks(scratch, f)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.join().validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error};
+    use super::{Join, JoinArray};
+
+    #[test]
+    fn encode_join() {
+        let tests: &[(&[u8], &str)] = &[
+            (&[1, 2, 3], "1, 2, 3"),
+            (&[42], "42"),
+            (&[], ""),
+        ];
+
+        for (v, s) in tests {
+            let j = Join::new(v, ", ");
+
+            assert_eq!(j.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(j.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn join_buffer_length() {
+        let j = Join::new(&["abc", "def"], ", ");
+
+        let mut buff = [0u8; 6];
+        assert_eq!(j.write(&mut buff), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn encode_join_array() {
+        let mut buff = [0u8; 32];
+
+        let j = JoinArray::<u8, 0>::new(&[], ", ");
+        assert_eq!(j.len(), 0);
+        assert_eq!(j.write_str(&mut buff), Ok(""));
+
+        let j = JoinArray::new(&[7u8], ", ");
+        assert_eq!(j.len(), 1);
+        assert_eq!(j.write_str(&mut buff), Ok("7"));
+
+        let j = JoinArray::new(&[1u8, 2, 3], ", ");
+        assert_eq!(j.len(), 7);
+        assert_eq!(j.write_str(&mut buff), Ok("1, 2, 3"));
+
+        let j = JoinArray::<&str, 0>::new(&[], "/");
+        assert_eq!(j.write_str(&mut buff), Ok(""));
+
+        let j = JoinArray::new(&["abc"], "/");
+        assert_eq!(j.write_str(&mut buff), Ok("abc"));
+
+        let j = JoinArray::new(&["abc", "", "µs"], "/");
+        assert_eq!(j.len(), 8);
+        assert_eq!(j.write_str(&mut buff), Ok("abc//µs"));
+
+        assert_eq!(JoinArray::new(&[1u8, 2, 3], ", ").write(&mut buff[..6]), Err(Error::BufferLength));
+    }
+}
@@ -1,10 +1,127 @@
 //! Helpers for more complex string encodings
 
 mod fractional;
-pub use fractional::Fractional;
+pub use fractional::{Fractional, FractionalFmt};
+
+mod number_format;
+pub use number_format::NumberFormat;
+
+#[cfg(feature = "float")]
+mod float;
+#[cfg(feature = "float")]
+pub use float::{Float, SpecialFloatNames};
+#[cfg(feature = "float")]
+mod scientific;
+#[cfg(feature = "float")]
+pub use scientific::Scientific;
 
 mod hex;
-pub use hex::Hex;
+pub use hex::{Hex, HexUpper, HexExt, Hex0x, HexInt, HexBytes, Endian, HexEncoder, decode_hex};
 
 mod pad;
-pub use pad::{Pad, PadLeft, PadRight};
+pub use pad::{Pad, PadLeft, PadRight, ZeroPad};
+
+mod value_unit;
+pub use value_unit::ValueUnit;
+
+mod ansi;
+pub use ansi::{Ansi, Color, ColorNum};
+
+mod time;
+pub use time::{TimeMs, HmsTime, DurationFmt, Millis};
+
+mod uuid;
+pub use uuid::{Uuid, BleUuid};
+
+mod size;
+pub use size::{HumanBytes, DataSize};
+
+mod grouped;
+pub use grouped::Grouped;
+
+mod status;
+pub use status::Status;
+
+mod decimal_col;
+pub use decimal_col::SignedDecimalCol;
+
+mod fractional_aligned;
+pub use fractional_aligned::FractionalAligned;
+
+mod bool_summary;
+pub use bool_summary::BoolSummary;
+
+mod escape;
+pub use escape::{Escape, JsonStr, Quoted};
+
+mod base64;
+pub use base64::{Base64, Base64Url};
+
+mod percent;
+pub use percent::PercentEncode;
+
+mod percentage;
+pub use percentage::{Percent, BasisPoints};
+
+mod join;
+pub use join::{Join, JoinArray};
+
+mod wrap;
+pub use wrap::{Wrap, Prefix, Suffix};
+
+mod bytes;
+pub use bytes::{Bytes, AsciiByte};
+
+mod case;
+pub use case::{AsciiLower, AsciiUpper, Capitalize};
+
+mod newlines;
+pub use newlines::Newlines;
+
+mod bounded;
+pub use bounded::Bounded;
+
+mod chunked;
+pub use chunked::Chunked;
+
+mod num_col;
+pub use num_col::NumCol;
+
+mod checksum;
+pub use checksum::WithChecksum;
+
+mod money;
+pub use money::Money;
+
+mod tagged;
+pub use tagged::Tagged;
+
+mod display;
+pub use display::FromDisplay;
+
+mod flags;
+pub use flags::Flags;
+
+mod trim;
+pub use trim::TrimEnd;
+
+mod hexdump;
+pub use hexdump::HexDump;
+
+mod ellipsize;
+pub use ellipsize::Ellipsize;
+
+mod progress;
+pub use progress::ProgressBar;
+
+mod ratio;
+pub use ratio::Ratio;
+
+mod as_str;
+pub use as_str::Str;
+
+mod discriminant;
+pub use discriminant::{Discriminant, AsDiscriminant};
+
+mod column;
+pub use column::Column;
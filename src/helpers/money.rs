@@ -0,0 +1,172 @@
+//! [Money] helper for displaying fixed point currency values
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Money, NumberFormat}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let m = Money::new(123450i32, "$");
+//! assert_eq!(m.write_str(&mut buff).unwrap(), "$1,234.50");
+//! 
+//! let m = Money::new(123450i32, " €").suffix().format(NumberFormat::EUROPEAN);
+//! assert_eq!(m.write_str(&mut buff).unwrap(), "1.234,50 €");
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::{Grouped, NumberFormat, fractional::Number};
+
+/// Number of minor units per major unit (cents per dollar)
+const MINOR: u128 = 100;
+
+/// Helper for encoding currency values from minor units (cents) with two
+/// fixed decimal places, grouped integer digits, and a currency symbol.
+/// 
+/// Symbols are written verbatim before (or with [Money::suffix], after) the
+/// amount, with any -ve sign leading the value (`-$5.00`, `-5.00 €`).
+pub struct Money<'a, N: Number> {
+    value: N,
+    symbol: &'a str,
+    suffix: bool,
+    format: NumberFormat<'a>,
+}
+
+impl <'a, N: Number> Money<'a, N> {
+    /// Create a new money value from minor units with a prefix symbol,
+    /// using the [NumberFormat::DEFAULT] separators
+    pub const fn new(value: N, symbol: &'a str) -> Self {
+        Self{
+            value,
+            symbol,
+            suffix: false,
+            format: NumberFormat::DEFAULT,
+        }
+    }
+
+    /// Write the symbol after the amount
+    pub const fn suffix(mut self) -> Self {
+        self.suffix = true;
+        self
+    }
+
+    /// Set the decimal and group separators
+    pub const fn separators(mut self, decimal: &'a str, group: &'a str) -> Self {
+        self.format.decimal = decimal;
+        self.format.group = group;
+        self
+    }
+
+    /// Set the number format (separators and group size)
+    pub const fn format(mut self, format: NumberFormat<'a>) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Split into sign and absolute major and minor units
+    fn parts(&self) -> (bool, u128, u8) {
+        let v = self.value.to_i128().unwrap_or(0);
+        let a = v.unsigned_abs();
+
+        (v < 0, a / MINOR, (a % MINOR) as u8)
+    }
+}
+
+/// [EncodeStr] implementation for [Money]
+impl <'a, N: Number> EncodeStr for Money<'a, N> {
+    fn len(&self) -> usize {
+        let (negative, major, _) = self.parts();
+
+        negative as usize
+            + self.symbol.len()
+            + Grouped::new(major).format(self.format).len()
+            + self.format.decimal.len()
+            + 2
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let (negative, major, minor) = self.parts();
+        let mut n = 0;
+
+        // Write -ve sign
+        if negative {
+            n += '-'.write(buff)?;
+        }
+
+        // Write prefix symbol
+        if !self.suffix {
+            n += self.symbol.write(&mut buff[n..])?;
+        }
+
+        // Write grouped major units
+        n += Grouped::new(major).format(self.format).write(&mut buff[n..])?;
+
+        // Write zero padded minor units
+        n += self.format.decimal.write(&mut buff[n..])?;
+        if minor < 10 {
+            n += '0'.write(&mut buff[n..])?;
+        }
+        n += minor.write(&mut buff[n..])?;
+
+        // Write suffix symbol
+        if self.suffix {
+            n += self.symbol.write(&mut buff[n..])?;
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_money() {
+        let tests = &[
+            (Money::new(0i32, "$"), "$0.00"),
+            (Money::new(5i32, "$"), "$0.05"),
+            (Money::new(123450i32, "$"), "$1,234.50"),
+            (Money::new(-500i32, "$"), "-$5.00"),
+            (Money::new(-5i32, "$"), "-$0.05"),
+            (Money::new(123456789i32, "£"), "£1,234,567.89"),
+            (Money::new(123450i32, " €").suffix().separators(",", "."), "1.234,50 €"),
+            (Money::new(-500i32, " €").suffix().separators(",", "."), "-5,00 €"),
+            (Money::new(99i32, "kr").suffix(), "0.99kr"),
+        ];
+
+        for (m, s) in tests {
+            assert_eq!(m.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 64];
+            assert_eq!(m.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_money_limits() {
+        let mut buff = [0u8; 64];
+
+        let m = Money::new(i64::MIN, "$");
+        assert_eq!(m.write_str(&mut buff), Ok("-$92,233,720,368,547,758.08"));
+        assert_eq!(m.len(), 27);
+    }
+
+    #[test]
+    fn encode_money_format() {
+        let tests = &[
+            (Money::new(123456789i32, "$").format(NumberFormat::EUROPEAN), "$1.234.567,89"),
+            (Money::new(123456789i32, " €").suffix().format(NumberFormat::new(",", "\u{202f}", 3)), "1\u{202f}234\u{202f}567,89 €"),
+            (Money::new(123456789i32, "₹").format(NumberFormat::new(".", ",", 2)), "₹1,23,45,67.89"),
+        ];
+
+        for (m, s) in tests {
+            assert_eq!(m.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 64];
+            assert_eq!(m.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! [Newlines] helper for normalising line endings
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Newlines};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let n = Newlines::lf("a\r\nb\rc\n");
+//! let s = n.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "a\nb\nc\n");
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Helper for encoding strings with any `\r\n`, `\r`, or `\n` line ending
+/// replaced by a single target terminator
+pub struct Newlines<'a> {
+    value: &'a str,
+    target: &'static str,
+}
+
+impl <'a> Newlines<'a> {
+    /// Create a new helper normalising line endings to `\n`
+    pub const fn lf(value: &'a str) -> Self {
+        Self{
+            value,
+            target: "\n",
+        }
+    }
+
+    /// Create a new helper normalising line endings to `\r\n`
+    pub const fn crlf(value: &'a str) -> Self {
+        Self{
+            value,
+            target: "\r\n",
+        }
+    }
+
+    /// Iterate over segments of the input, returning the segment length
+    /// and whether it is a line ending
+    fn segments(&self) -> impl Iterator<Item=(usize, bool)> + '_ {
+        let b = self.value.as_bytes();
+        let mut i = 0;
+
+        core::iter::from_fn(move || {
+            let s = match b.get(i..)? {
+                [] => return None,
+                [b'\r', b'\n', ..] => (2, true),
+                [b'\r' | b'\n', ..] => (1, true),
+                r => (r.iter().take_while(|c| **c != b'\r' && **c != b'\n').count(), false),
+            };
+            i += s.0;
+            Some(s)
+        })
+    }
+}
+
+/// [EncodeStr] implementation for [Newlines]
+impl <'a> EncodeStr for Newlines<'a> {
+    fn len(&self) -> usize {
+        self.segments().map(|(n, eol)| match eol {
+            true => self.target.len(),
+            false => n,
+        }).sum()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let b = self.value.as_bytes();
+        let (mut i, mut n) = (0, 0);
+
+        for (l, eol) in self.segments() {
+            // Write target terminator or copy line content
+            match eol {
+                true => n += self.target.write(&mut buff[n..])?,
+                false => {
+                    buff[n..][..l].copy_from_slice(&b[i..][..l]);
+                    n += l;
+                },
+            }
+            i += l;
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::Newlines;
+
+    #[test]
+    fn encode_newlines() {
+        for v in ["a\r\nb", "a\rb", "a\nb"] {
+            let mut buff = [0u8; 32];
+
+            assert_eq!(Newlines::lf(v).len(), 3);
+            assert_eq!(Newlines::lf(v).write_str(&mut buff), Ok("a\nb"));
+
+            assert_eq!(Newlines::crlf(v).len(), 4);
+            assert_eq!(Newlines::crlf(v).write_str(&mut buff), Ok("a\r\nb"));
+        }
+    }
+
+    #[test]
+    fn encode_newlines_mixed() {
+        let tests = &[
+            ("", ""),
+            ("plain", "plain"),
+            ("\r\n\r\n", "\n\n"),
+            ("\n\r", "\n\n"),
+            ("a\r\r\nb\n", "a\n\nb\n"),
+        ];
+
+        for (v, s) in tests {
+            let n = Newlines::lf(v);
+
+            assert_eq!(n.len(), s.len());
+
+            let mut buff = [0u8; 32];
+            assert_eq!(n.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! [NumCol] helper for right-aligning numbers in fixed width columns
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::NumCol};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let c = NumCol::new(-42i32, 5);
+//! assert_eq!(c.write_str(&mut buff).unwrap(), "  -42");
+//! ```
+
+use num_traits::PrimInt;
+
+use crate::{EncodeStr, Error};
+use super::PadLeft;
+
+/// Helper for right-aligning integers within a column of `width` characters,
+/// space padded on the left with the `-` sign kept adjacent to the digits.
+/// 
+/// Values wider than the column extend it rather than being truncated.
+pub struct NumCol<N: EncodeStr + PrimInt> {
+    value: N,
+    width: usize,
+}
+
+impl <N: EncodeStr + PrimInt> NumCol<N> {
+    /// Create a new numeric column with the provided value and width
+    pub const fn new(value: N, width: usize) -> Self {
+        Self{
+            value,
+            width,
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [NumCol]
+impl <N: EncodeStr + PrimInt> EncodeStr for NumCol<N> {
+    fn len(&self) -> usize {
+        self.width.max(self.value.len())
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        PadLeft::new(self.value, self.width, ' ').write(buff)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_num_col() {
+        let tests = &[
+            (NumCol::new(42i32, 5), "   42"),
+            (NumCol::new(-42i32, 5), "  -42"),
+            (NumCol::new(0i32, 3), "  0"),
+            (NumCol::new(-12345i32, 5), "-12345"),
+            (NumCol::new(1234567i32, 3), "1234567"),
+        ];
+
+        for (c, s) in tests {
+            let mut buff = [0u8; 32];
+
+            assert_eq!(c.len(), s.len());
+            assert_eq!(c.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! [NumberFormat] configuration for localised decimal and group separators
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Fractional, Grouped, NumberFormat}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let f = Fractional::new(123456i32, 100).with_format(NumberFormat::EUROPEAN);
+//! assert_eq!(f.write_str(&mut buff).unwrap(), "1.234,56");
+//! 
+//! let g = Grouped::new(1234567u32).format(NumberFormat::new(".", "\u{202f}", 3));
+//! assert_eq!(g.write_str(&mut buff).unwrap(), "1\u{202f}234\u{202f}567");
+//! ```
+
+/// Number formatting configuration, specifying the decimal separator,
+/// digit group separator, and number of digits per group.
+/// 
+/// Separators are strings to support multi-byte characters such as a
+/// narrow no-break space (`"\u{202f}"`), a group size of zero disables grouping.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NumberFormat<'a> {
+    /// Separator between integer and decimal parts
+    pub decimal: &'a str,
+    /// Separator between groups of integer digits
+    pub group: &'a str,
+    /// Number of digits per group
+    pub group_size: usize,
+}
+
+impl NumberFormat<'static> {
+    /// Default format, `1,234.56`
+    pub const DEFAULT: Self = Self::new(".", ",", 3);
+
+    /// Common european format, `1.234,56`
+    pub const EUROPEAN: Self = Self::new(",", ".", 3);
+}
+
+impl <'a> NumberFormat<'a> {
+    /// Create a new number format with the provided separators and group size
+    pub const fn new(decimal: &'a str, group: &'a str, group_size: usize) -> Self {
+        Self{
+            decimal,
+            group,
+            group_size,
+        }
+    }
+}
+
+/// Default [NumberFormat] implementation, see [NumberFormat::DEFAULT]
+impl Default for NumberFormat<'static> {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -1,12 +1,21 @@
 use core::marker::PhantomData;
 
-use crate::{EncodeStr, Error};
+use crate::{EncodeStr, Error, WriteMode};
 
-/// Helper for padding string encodable types
-pub struct Pad<E: EncodeStr, M> {
+/// Scratch length for encoding fill patterns
+const FILL_SCRATCH_LEN: usize = 32;
+
+/// Helper for padding string encodable types to a minimum width, using a
+/// [char] or a repeating [EncodeStr] fill pattern
+/// 
+/// Padding is applied outside of the inner value, so zero-padding signed
+/// values with [PadLeft] places the sign after the padding
+/// (`PadLeft::new(-5i32, 4, '0')` -> `00-5`), see [ZeroPad] for sign-aware
+/// zero padding (`-005`).
+pub struct Pad<E: EncodeStr, M, F: EncodeStr = char> {
     inner: E,
     width: usize,
-    pad: char,
+    pad: F,
     mode: PhantomData<M>
 }
 
@@ -18,10 +27,10 @@ pub struct Right;
 
 
 /// Left padding, see [Pad]
-pub type PadLeft<E> = Pad<E, Left>;
+pub type PadLeft<E, F = char> = Pad<E, Left, F>;
 
 /// Right padding, see [Pad]
-pub type PadRight<E> = Pad<E, Right>;
+pub type PadRight<E, F = char> = Pad<E, Right, F>;
 
 
 impl <E: EncodeStr, M> Pad<E, M> {
@@ -36,8 +45,104 @@ impl <E: EncodeStr, M> Pad<E, M> {
     }
 }
 
+impl <E: EncodeStr, M, F: EncodeStr> Pad<E, M, F> {
+    /// Create a new pad wrapper using a repeating fill pattern (eg. `"- "`),
+    /// with the final repetition truncated to reach the width.
+    /// 
+    /// Where truncation would split a multi-byte character the remaining bytes
+    /// are filled with spaces. Patterns over 32 bytes are streamed via
+    /// [EncodeStr::write_chunks], so must support chunked encoding (as for
+    /// strings, tuples, and [Hex](crate::helpers::Hex)).
+    pub const fn with_fill(inner: E, width: usize, fill: F) -> Self {
+        Self{
+            inner,
+            width,
+            pad: fill,
+            mode: PhantomData,
+        }
+    }
+
+    /// Write `buff.len()` bytes of the fill pattern
+    fn write_fill(&self, buff: &mut [u8]) -> Result<(), Error> {
+        // Nothing to fill
+        if buff.is_empty() {
+            return Ok(())
+        }
+
+        // Empty patterns fall back to spaces
+        let f = self.pad.len();
+        if f == 0 {
+            buff.fill(b' ');
+            return Ok(())
+        }
+
+        // Stream longer patterns in chunks
+        if f > FILL_SCRATCH_LEN {
+            return self.write_fill_chunked(buff);
+        }
+
+        let mut scratch = [0u8; FILL_SCRATCH_LEN];
+        let f = self.pad.write(&mut scratch)?;
+
+        // Cycle pattern to fill the buffer
+        for (i, b) in buff.iter_mut().enumerate() {
+            *b = scratch[i % f];
+        }
+
+        // Replace any partial trailing character
+        let partial = buff.len() % f;
+        let mut boundary = partial;
+        while boundary > 0 && is_continuation(scratch[boundary]) {
+            boundary -= 1;
+        }
+
+        let p = buff.len();
+        buff[p - partial + boundary..].fill(b' ');
+
+        Ok(())
+    }
+
+    /// Write `buff.len()` bytes of a fill pattern longer than the scratch
+    /// buffer, cycling [EncodeStr::write_chunks] output
+    fn write_fill_chunked(&self, buff: &mut [u8]) -> Result<(), Error> {
+        let mut scratch = [0u8; FILL_SCRATCH_LEN];
+        let mut n = 0;
+
+        while n < buff.len() {
+            let start = n;
+
+            self.pad.write_chunks(&mut scratch, |chunk| {
+                let c = crate::floor_char_boundary(chunk.as_bytes(), buff.len() - n);
+                buff[n..][..c].copy_from_slice(&chunk.as_bytes()[..c]);
+                n += c;
+
+                // Replace any partial trailing character
+                if c < chunk.len() {
+                    buff[n..].fill(b' ');
+                    n = buff.len();
+                }
+
+                Ok(())
+            })?;
+
+            // Guard against patterns encoding nothing
+            if n == start {
+                buff[n..].fill(b' ');
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Check whether a byte is a UTF-8 continuation byte
+const fn is_continuation(b: u8) -> bool {
+    b & 0xc0 == 0x80
+}
+
 /// [EncodeStr] for [PadRight]
-impl <E: EncodeStr> EncodeStr for PadRight<E> {
+impl <E: EncodeStr, F: EncodeStr> EncodeStr for PadRight<E, F> {
     fn len(&self) -> usize {
         self.width.max(self.inner.len())
     }
@@ -46,20 +151,45 @@ impl <E: EncodeStr> EncodeStr for PadRight<E> {
         let n = self.inner.len();
         let m = self.width.max(n);
 
+        // Check buffer length
+        if buff.len() < m {
+            return Err(Error::BufferLength)
+        }
+
         // Write inner value
         self.inner.write(buff)?;
 
         // Pad remaining space
-        for i in n..m {
-            buff[i] = self.pad as u8;
+        self.write_fill(&mut buff[n..m])?;
+
+        Ok(m)
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        if mode == WriteMode::Strict || self.len() <= buff.len() {
+            return self.write(buff);
         }
 
+        // Truncate inner value, then fill any remaining space
+        let mut n = 0;
+        if !crate::write_part_truncated(&self.inner, buff, &mut n)? {
+            return Ok(n)
+        }
+
+        let m = self.width.min(buff.len());
+        self.write_fill(&mut buff[n..m])?;
+
         Ok(m)
     }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.inner.validate()?;
+        self.pad.validate()
+    }
 }
 
 /// [EncodeStr] for [PadLeft]
-impl <E: EncodeStr> EncodeStr for PadLeft<E> {
+impl <E: EncodeStr, F: EncodeStr> EncodeStr for PadLeft<E, F> {
     fn len(&self) -> usize {
         self.width.max(self.inner.len())
     }
@@ -68,21 +198,101 @@ impl <E: EncodeStr> EncodeStr for PadLeft<E> {
         let n = self.inner.len();
         let p = self.width.max(n) - n;
 
-        // Write padding
-        for i in 0..p {
-            buff[i] = self.pad as u8;
+        // Check buffer length
+        if buff.len() < n + p {
+            return Err(Error::BufferLength)
         }
 
+        // Write padding
+        self.write_fill(&mut buff[..p])?;
+
         // Write inner value
         self.inner.write(&mut buff[p..])?;
 
         Ok(n + p)
     }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        if mode == WriteMode::Strict || self.len() <= buff.len() {
+            return self.write(buff);
+        }
+
+        // Truncate padding, then the inner value where space remains
+        let n = self.inner.len();
+        let p = self.width.max(n) - n;
+
+        let mut m = p.min(buff.len());
+        self.write_fill(&mut buff[..m])?;
+
+        if m == p {
+            crate::write_part_truncated(&self.inner, buff, &mut m)?;
+        }
+
+        Ok(m)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.inner.validate()?;
+        self.pad.validate()
+    }
+}
+
+/// Helper for sign-aware zero padding of numeric types, keeping any leading
+/// sign (`-` or `+`) leftmost (`ZeroPad::new(-5i32, 4)` -> `-005`)
+pub struct ZeroPad<E: EncodeStr> {
+    inner: E,
+    width: usize,
+}
+
+impl <E: EncodeStr> ZeroPad<E> {
+    /// Create a new zero pad wrapper with the provided inner encoder and width
+    pub const fn new(inner: E, width: usize) -> Self {
+        Self{
+            inner,
+            width,
+        }
+    }
+}
+
+/// [EncodeStr] for [ZeroPad]
+impl <E: EncodeStr> EncodeStr for ZeroPad<E> {
+    fn len(&self) -> usize {
+        self.width.max(self.inner.len())
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let n = self.inner.len();
+        let p = self.width.max(n) - n;
+
+        // Check buffer length
+        if buff.len() < n + p {
+            return Err(Error::BufferLength)
+        }
+
+        // Write inner value after the padding
+        self.inner.write(&mut buff[p..])?;
+
+        // Move any sign to the start, then fill with zeroes
+        let s = match buff.get(p) {
+            Some(c @ (b'-' | b'+')) if p > 0 => {
+                buff[0] = *c;
+                1
+            },
+            _ => 0,
+        };
+        buff[s..p + s].fill(b'0');
+
+        Ok(n + p)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.inner.validate()
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::write_str;
+    use crate::{write_str, helpers::Bounded};
 
     use super::*;
 
@@ -115,4 +325,114 @@ mod test {
             assert_eq!(v, Ok(*s));
         }
     }
+
+    #[test]
+    fn test_pad_pattern() {
+        let mut buff = [0u8; 32];
+
+        let tests = &[
+            (PadRight::with_fill("abc", 8, "- "), "abc- - -"),
+            (PadRight::with_fill("abc", 9, "- "), "abc- - - "),
+            (PadRight::with_fill("abc", 2, "- "), "abc"),
+            (PadRight::with_fill("abc", 7, "=-+"), "abc=-+="),
+        ];
+
+        for (p, s) in tests {
+            assert_eq!(p.len(), s.len());
+            assert_eq!(write_str!(&mut buff[..], p), Ok(*s));
+        }
+
+        let tests = &[
+            (PadLeft::with_fill("abc", 8, "- "), "- - -abc"),
+            (PadLeft::with_fill("abc", 9, ". "), ". . . abc"),
+        ];
+
+        for (p, s) in tests {
+            assert_eq!(p.len(), s.len());
+            assert_eq!(write_str!(&mut buff[..], p), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn test_pad_long_fill() {
+        let mut buff = [0u8; 128];
+        let pattern = "0123456789".repeat(4);
+
+        // No fill required
+        let p = PadRight::with_fill("abc", 3, pattern.as_str());
+        assert_eq!(p.write_str(&mut buff), Ok("abc"));
+
+        // Patterns over the scratch length are cycled in chunks
+        let p = PadRight::with_fill("abc", 90, pattern.as_str());
+        let expected = ["abc", &pattern, &pattern, &pattern[..7]].concat();
+        assert_eq!(p.len(), 90);
+        assert_eq!(p.write_str(&mut buff), Ok(expected.as_str()));
+
+        // Partial multi-byte characters are replaced with spaces
+        let pattern = "µ".repeat(20);
+        let p = PadLeft::with_fill("a", 42, pattern.as_str());
+        let expected = [&pattern, " a"].concat();
+        assert_eq!(p.write_str(&mut buff), Ok(expected.as_str()));
+
+        // Long patterns without chunked encoding are limited to the scratch length
+        let p = PadRight::with_fill("a", 50, Bounded::new(pattern.as_str(), 64));
+        assert_eq!(p.write_str(&mut buff), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn test_pad_multibyte_fill() {
+        let mut buff = [0u8; 32];
+
+        // Whole repetitions of a multi-byte char
+        let p = PadLeft::new(12u8, 8, '·');
+        assert_eq!(p.len(), 8);
+        assert_eq!(p.write_str(&mut buff), Ok("···12"));
+
+        // Partial repetitions are filled with spaces
+        let p = PadLeft::new(12u8, 5, '·');
+        assert_eq!(p.len(), 5);
+        assert_eq!(p.write_str(&mut buff), Ok("· 12"));
+
+        // Encodable fill patterns
+        let p = PadRight::with_fill('x', 6, ('<', 0u8, '>'));
+        assert_eq!(p.write_str(&mut buff), Ok("x<0><0"));
+    }
+
+    #[test]
+    fn test_pad_buffer_length() {
+        let mut buff = [0u8; 4];
+
+        assert_eq!(PadLeft::new("abc", 5, ' ').write(&mut buff), Err(Error::BufferLength));
+        assert_eq!(PadRight::with_fill("abc", 5, "- ").write(&mut buff), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn test_zero_pad() {
+        use crate::helpers::Fractional;
+
+        let mut buff = [0u8; 32];
+
+        // PadLeft pads outside of the sign
+        assert_eq!(PadLeft::new(-5i32, 4, '0').write_str(&mut buff), Ok("00-5"));
+
+        let tests = &[
+            (ZeroPad::new(-5i32, 4), "-005"),
+            (ZeroPad::new(5i32, 4), "0005"),
+            (ZeroPad::new(-5i32, 2), "-5"),
+            (ZeroPad::new(-5i32, 1), "-5"),
+            (ZeroPad::new(-1234i32, 4), "-1234"),
+            (ZeroPad::new(0i32, 3), "000"),
+            (ZeroPad::new(i32::MIN + 1, 12), "-02147483647"),
+        ];
+
+        for (p, s) in tests {
+            assert_eq!(p.len(), s.len());
+            assert_eq!(p.write_str(&mut buff), Ok(*s));
+        }
+
+        // Sign-aware for any encoder
+        assert_eq!(ZeroPad::new(Fractional::new(-15i32, 10), 6).write_str(&mut buff), Ok("-001.5"));
+        assert_eq!(ZeroPad::new("+7", 4).write_str(&mut buff), Ok("+007"));
+        assert_eq!(ZeroPad::new(7u8, 3).write(&mut buff[..2]), Err(Error::BufferLength));
+    }
 }
@@ -0,0 +1,106 @@
+//! [PercentEncode] helper for URL (percent) encoding strings
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::PercentEncode};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let p = PercentEncode::new("a b&c");
+//! let s = p.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "a%20b%26c");
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::hex::HEX_MAP_UPPER;
+
+/// Helper for percent encoding strings, with all bytes outside of the
+/// RFC 3986 unreserved set (`A-Z a-z 0-9 - . _ ~`) encoded as `%XX`
+pub struct PercentEncode<'a> {
+    value: &'a str,
+    plus: bool,
+}
+
+impl <'a> PercentEncode<'a> {
+    /// Create a new percent encoder, encoding spaces as `%20`
+    pub const fn new(value: &'a str) -> Self {
+        Self{
+            value,
+            plus: false,
+        }
+    }
+
+    /// Create a new form (`application/x-www-form-urlencoded`) encoder, encoding spaces as `+`
+    pub const fn form(value: &'a str) -> Self {
+        Self{
+            value,
+            plus: true,
+        }
+    }
+
+    /// Encoded length of a single byte
+    fn encoded_len(&self, b: u8) -> usize {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => 1,
+            b' ' if self.plus => 1,
+            _ => 3,
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [PercentEncode]
+impl <'a> EncodeStr for PercentEncode<'a> {
+    fn len(&self) -> usize {
+        self.value.bytes().map(|b| self.encoded_len(b)).sum()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = 0;
+        for b in self.value.bytes() {
+            match (b, self.encoded_len(b)) {
+                // Write spaces as '+'
+                (b' ', 1) => buff[n] = b'+',
+                // Pass through unreserved characters
+                (_, 1) => buff[n] = b,
+                // Percent encode everything else
+                _ => {
+                    buff[n..][..3].copy_from_slice(&[b'%', HEX_MAP_UPPER[(b >> 4) as usize] as u8, HEX_MAP_UPPER[(b & 0x0F) as usize] as u8]);
+                },
+            }
+
+            n += self.encoded_len(b);
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::PercentEncode;
+
+    #[test]
+    fn encode_percent() {
+        let tests = &[
+            (PercentEncode::new("abc-123_.~"), "abc-123_.~"),
+            (PercentEncode::new("a b"), "a%20b"),
+            (PercentEncode::form("a b"), "a+b"),
+            (PercentEncode::new("k=v&x"), "k%3Dv%26x"),
+            (PercentEncode::form("a+b"), "a%2Bb"),
+            (PercentEncode::new("µ"), "%C2%B5"),
+            (PercentEncode::new("✓"), "%E2%9C%93"),
+        ];
+
+        for (p, s) in tests {
+            assert_eq!(p.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(p.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! [Percent] helper for displaying ratios as percentages or permille, and
+//! [BasisPoints] for displaying basis points as percentages
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Percent, BasisPoints}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let p = Percent::new(15u32, 100u32);
+//! assert_eq!(p.write_str(&mut buff), Ok("15%"));
+//! 
+//! let p = Percent::new(1u32, 8u32).decimals(1);
+//! assert_eq!(p.write_str(&mut buff), Ok("12.5%"));
+//! 
+//! let b = BasisPoints(25);
+//! assert_eq!(b.write_str(&mut buff), Ok("0.25%"));
+//! ```
+
+use num_traits::PrimInt;
+
+use crate::{EncodeStr, Error};
+use super::Fractional;
+
+/// Output for ratios that cannot be computed (zero denominators)
+const UNDEFINED: &str = "--";
+
+/// Helper for encoding a ratio (`numerator / denominator`) as a percentage
+/// (or permille) with a fixed number of decimal places and a trailing unit.
+/// 
+/// Values are truncated towards zero (`Percent::new(2, 3)` -> `66%`), and
+/// zero denominators (or values exceeding [i128]) are encoded as `--%`.
+pub struct Percent<N: PrimInt> {
+    num: N,
+    den: N,
+    decimals: u32,
+    scale: i128,
+    unit: char,
+}
+
+impl <N: PrimInt> Percent<N> {
+    /// Create a new percentage (`num * 100 / den`) with no decimal places
+    pub const fn new(num: N, den: N) -> Self {
+        Self{
+            num,
+            den,
+            decimals: 0,
+            scale: 100,
+            unit: '%',
+        }
+    }
+
+    /// Create a new permille (`num * 1000 / den`) with no decimal places
+    pub const fn permille(num: N, den: N) -> Self {
+        Self{
+            num,
+            den,
+            decimals: 0,
+            scale: 1000,
+            unit: '‰',
+        }
+    }
+
+    /// Set the number of decimal places to be emitted (defaults to 0)
+    pub const fn decimals(mut self, decimals: u32) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Compute the scaled value, returning `None` for undefined ratios
+    fn value(&self) -> Option<Fractional<i128>> {
+        let num = self.num.to_i128()?;
+        let den = self.den.to_i128().filter(|d| *d != 0)?;
+        let divisor = 10i128.checked_pow(self.decimals)?;
+
+        let scaled = num.checked_mul(self.scale)?.checked_mul(divisor)? / den;
+
+        Some(Fractional::fixed(scaled, divisor))
+    }
+}
+
+/// [EncodeStr] implementation for [Percent]
+impl <N: PrimInt> EncodeStr for Percent<N> {
+    fn len(&self) -> usize {
+        match self.value() {
+            Some(v) => v.len() + self.unit.len(),
+            None => UNDEFINED.len() + self.unit.len(),
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = match self.value() {
+            Some(v) => v.write(buff)?,
+            None => UNDEFINED.write(buff)?,
+        };
+
+        n += self.unit.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+/// Helper for encoding basis points (1/100th of a percent) as a percentage
+/// with up to two decimal places (`BasisPoints(25)` -> `0.25%`), trailing
+/// zeroes are omitted (`BasisPoints(-50)` -> `-0.5%`)
+pub struct BasisPoints(pub i32);
+
+impl BasisPoints {
+    /// Fetch the percentage value
+    const fn value(&self) -> Fractional<i32> {
+        Fractional::new(self.0, 100)
+    }
+}
+
+/// [EncodeStr] implementation for [BasisPoints]
+impl EncodeStr for BasisPoints {
+    fn len(&self) -> usize {
+        self.value().len() + 1
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = self.value().write(buff)?;
+        n += '%'.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error};
+    use super::{Percent, BasisPoints};
+
+    #[test]
+    fn encode_percent() {
+        let tests = &[
+            (Percent::new(0u32, 100), "0%"),
+            (Percent::new(15u32, 100), "15%"),
+            (Percent::new(100u32, 100), "100%"),
+            (Percent::new(250u32, 100), "250%"),
+            (Percent::new(2u32, 3), "66%"),
+            (Percent::new(1u32, 8).decimals(1), "12.5%"),
+            (Percent::new(1u32, 8).decimals(3), "12.500%"),
+            (Percent::new(0u32, 8).decimals(2), "0.00%"),
+            (Percent::new(100u32, 100).decimals(1), "100.0%"),
+            (Percent::new(5u32, 0), "--%"),
+        ];
+
+        for (p, s) in tests {
+            assert_eq!(p.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(p.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_percent_signed() {
+        let tests = &[
+            (Percent::new(-1i64, 8).decimals(1), "-12.5%"),
+            (Percent::new(-1i64, 400).decimals(1), "-0.2%"),
+            (Percent::new(i64::MAX, i64::MAX), "100%"),
+        ];
+
+        for (p, s) in tests {
+            assert_eq!(p.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(p.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_permille() {
+        let tests = &[
+            (Percent::permille(0usize, 10), "0‰"),
+            (Percent::permille(15usize, 1000), "15‰"),
+            (Percent::permille(1usize, 8).decimals(1), "125.0‰"),
+            (Percent::permille(1usize, 0), "--‰"),
+        ];
+
+        for (p, s) in tests {
+            assert_eq!(p.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(p.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_percent_buffer_length() {
+        let mut buff = [0u8; 3];
+        assert_eq!(Percent::new(100u8, 100).write(&mut buff), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn encode_basis_points() {
+        let tests = &[
+            (BasisPoints(0), "0%"),
+            (BasisPoints(1), "0.01%"),
+            (BasisPoints(25), "0.25%"),
+            (BasisPoints(150), "1.5%"),
+            (BasisPoints(10000), "100%"),
+            (BasisPoints(12345), "123.45%"),
+            (BasisPoints(-50), "-0.5%"),
+            (BasisPoints(-10025), "-100.25%"),
+            (BasisPoints(i32::MAX), "21474836.47%"),
+            (BasisPoints(i32::MIN), "-21474836.48%"),
+        ];
+
+        for (b, s) in tests {
+            assert_eq!(b.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(b.write_str(&mut buff), Ok(*s));
+        }
+
+        let mut buff = [0u8; 4];
+        assert_eq!(BasisPoints(25).write(&mut buff), Err(Error::BufferLength));
+    }
+}
@@ -0,0 +1,156 @@
+//! [ProgressBar] helper for displaying progress as a fixed width bar
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::ProgressBar};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let p = ProgressBar::new(50, 100, 8);
+//! assert_eq!(p.write_str(&mut buff), Ok("[####----]"));
+//! 
+//! let p = ProgressBar::new(1, 3, 6).chars('█', '░').no_brackets();
+//! assert_eq!(p.write_str(&mut buff), Ok("██░░░░"));
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Helper for encoding `current / total` as a progress bar of `width` cells
+/// filled from the left (`[####----]`), with optional surrounding brackets.
+/// 
+/// Filled cells are truncated (so the bar is only full at completion),
+/// `current` values over `total` are clamped to a full bar, and a `total`
+/// of zero is displayed as an empty bar.
+pub struct ProgressBar {
+    current: u64,
+    total: u64,
+    width: usize,
+    fill: char,
+    empty: char,
+    brackets: bool,
+}
+
+impl ProgressBar {
+    /// Create a new bracketed progress bar using `#` and `-` characters
+    pub const fn new(current: u64, total: u64, width: usize) -> Self {
+        Self{
+            current,
+            total,
+            width,
+            fill: '#',
+            empty: '-',
+            brackets: true,
+        }
+    }
+
+    /// Set the fill and empty characters
+    pub const fn chars(mut self, fill: char, empty: char) -> Self {
+        self.fill = fill;
+        self.empty = empty;
+        self
+    }
+
+    /// Disable the surrounding brackets
+    pub const fn no_brackets(mut self) -> Self {
+        self.brackets = false;
+        self
+    }
+
+    /// Compute the number of filled cells
+    fn filled(&self) -> usize {
+        if self.total == 0 {
+            return 0;
+        }
+
+        let current = self.current.min(self.total) as u128;
+        (current * self.width as u128 / self.total as u128) as usize
+    }
+}
+
+/// [EncodeStr] implementation for [ProgressBar]
+impl EncodeStr for ProgressBar {
+    fn len(&self) -> usize {
+        let f = self.filled();
+        let n = f * self.fill.len_utf8() + (self.width - f) * self.empty.len_utf8();
+
+        match self.brackets {
+            true => n + 2,
+            false => n,
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let f = self.filled();
+        let mut n = 0;
+
+        if self.brackets {
+            n += '['.write(&mut buff[n..])?;
+        }
+
+        for i in 0..self.width {
+            let c = match i < f {
+                true => self.fill,
+                false => self.empty,
+            };
+            n += c.write(&mut buff[n..])?;
+        }
+
+        if self.brackets {
+            n += ']'.write(&mut buff[n..])?;
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error};
+    use super::ProgressBar;
+
+    #[test]
+    fn encode_progress_bar() {
+        let tests = &[
+            (ProgressBar::new(0, 100, 8), "[--------]"),
+            (ProgressBar::new(50, 100, 8), "[####----]"),
+            (ProgressBar::new(100, 100, 8), "[########]"),
+            (ProgressBar::new(99, 100, 8), "[#######-]"),
+            (ProgressBar::new(1, 3, 4), "[#---]"),
+            // Over-full values are clamped
+            (ProgressBar::new(250, 100, 8), "[########]"),
+            // Zero totals are empty
+            (ProgressBar::new(5, 0, 4), "[----]"),
+            (ProgressBar::new(5, 10, 0), "[]"),
+            (ProgressBar::new(u64::MAX - 1, u64::MAX, 4), "[###-]"),
+        ];
+
+        for (p, s) in tests {
+            assert_eq!(p.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(p.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_progress_bar_config() {
+        let tests = &[
+            (ProgressBar::new(50, 100, 8).no_brackets(), "####----"),
+            (ProgressBar::new(3, 4, 4).chars('=', ' '), "[=== ]"),
+            (ProgressBar::new(1, 2, 4).chars('█', '░').no_brackets(), "██░░"),
+        ];
+
+        for (p, s) in tests {
+            assert_eq!(p.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(p.write_str(&mut buff), Ok(*s));
+        }
+
+        let mut buff = [0u8; 9];
+        assert_eq!(ProgressBar::new(1, 2, 8).write(&mut buff), Err(Error::BufferLength));
+    }
+}
@@ -0,0 +1,144 @@
+//! [Ratio] helper for displaying integer ratios as fractions
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Ratio};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let r = Ratio::new(6, 8);
+//! assert_eq!(r.write_str(&mut buff), Ok("6/8"));
+//! 
+//! let r = Ratio::new(6, 8).reduce();
+//! assert_eq!(r.write_str(&mut buff), Ok("3/4"));
+//! ```
+
+use num_traits::PrimInt;
+
+use crate::{EncodeStr, Error};
+
+/// Helper for encoding integer ratios as `num/den`, optionally reduced to
+/// lowest terms.
+/// 
+/// Any sign is placed on the whole ratio (`Ratio::new(3, -4)` -> `-3/4`),
+/// ratios with zero denominators are not reduced.
+pub struct Ratio<N: PrimInt> {
+    num: N,
+    den: N,
+    reduce: bool,
+}
+
+impl <N: PrimInt> Ratio<N> {
+    /// Create a new ratio
+    pub const fn new(num: N, den: N) -> Self {
+        Self{
+            num,
+            den,
+            reduce: false,
+        }
+    }
+
+    /// Reduce the ratio to lowest terms (`6/8` -> `3/4`)
+    pub const fn reduce(mut self) -> Self {
+        self.reduce = true;
+        self
+    }
+
+    /// Compute the (negative, numerator, denominator) parts for encoding
+    fn parts(&self) -> (bool, u128, u128) {
+        let negative = (self.num < N::zero()) != (self.den < N::zero()) && !self.num.is_zero();
+        let (mut num, mut den) = (magnitude(self.num), magnitude(self.den));
+
+        if self.reduce && den != 0 {
+            let g = gcd(num, den);
+            num /= g;
+            den /= g;
+        }
+
+        (negative, num, den)
+    }
+}
+
+/// Fetch the absolute value of an integer as a [u128]
+fn magnitude<N: PrimInt>(v: N) -> u128 {
+    match v.to_u128() {
+        Some(v) => v,
+        None => v.to_i128().unwrap_or(0).unsigned_abs(),
+    }
+}
+
+/// Compute the greatest common divisor of two values
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// [EncodeStr] implementation for [Ratio]
+impl <N: PrimInt> EncodeStr for Ratio<N> {
+    fn len(&self) -> usize {
+        let (negative, num, den) = self.parts();
+
+        negative as usize + num.len() + 1 + den.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let (negative, num, den) = self.parts();
+        let mut n = 0;
+
+        if negative {
+            n += '-'.write(&mut buff[n..])?;
+        }
+
+        n += num.write(&mut buff[n..])?;
+        n += '/'.write(&mut buff[n..])?;
+        n += den.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error};
+    use super::Ratio;
+
+    #[test]
+    fn encode_ratio() {
+        let tests = &[
+            (Ratio::new(6, 8), "6/8"),
+            (Ratio::new(6, 8).reduce(), "3/4"),
+            (Ratio::new(3, 4).reduce(), "3/4"),
+            (Ratio::new(7, 13).reduce(), "7/13"),
+            (Ratio::new(10, 5).reduce(), "2/1"),
+            (Ratio::new(0, 8).reduce(), "0/1"),
+            (Ratio::new(5, 0).reduce(), "5/0"),
+            // Signs are placed on the whole ratio
+            (Ratio::new(-6, 8), "-6/8"),
+            (Ratio::new(6, -8).reduce(), "-3/4"),
+            (Ratio::new(-6, -8).reduce(), "3/4"),
+            (Ratio::new(0, -8), "0/8"),
+            (Ratio::new(i32::MIN, 2).reduce(), "-1073741824/1"),
+        ];
+
+        for (r, s) in tests {
+            assert_eq!(r.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(r.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_ratio_unsigned() {
+        let mut buff = [0u8; 48];
+
+        assert_eq!(Ratio::new(1920u16, 1080).reduce().write_str(&mut buff), Ok("16/9"));
+        assert_eq!(Ratio::new(u64::MAX, 5).reduce().write_str(&mut buff), Ok("3689348814741910323/1"));
+        assert_eq!(Ratio::new(3u8, 4).write(&mut buff[..2]), Err(Error::BufferLength));
+    }
+}
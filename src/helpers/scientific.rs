@@ -0,0 +1,156 @@
+//! [Scientific] helper for displaying floating point values in scientific notation
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Scientific};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let s = Scientific::new(12300.0, 3);
+//! assert_eq!(s.write_str(&mut buff).unwrap(), "1.23e4");
+//! 
+//! let s = Scientific::new(-0.00045, 3);
+//! assert_eq!(s.write_str(&mut buff).unwrap(), "-4.5e-4");
+//! ```
+//! 
+//! The mantissa is normalised to a single digit before the decimal point and
+//! rounded to the configured precision, with trailing zeroes trimmed.
+//! Zero is encoded as `0e0`, NaN and infinities as `NaN`, `inf` and `-inf`
+//! by default (see [Scientific::names]).
+
+use crate::{EncodeStr, Error};
+use super::{Fractional, SpecialFloatNames};
+
+/// Maximum number of mantissa decimal places
+const MAX_PRECISION: u32 = 17;
+
+/// Helper for encoding floating point values in scientific notation (`m.ddde±X`)
+pub struct Scientific {
+    value: f64,
+    precision: u32,
+    names: SpecialFloatNames,
+}
+
+impl Scientific {
+    /// Create a new scientific wrapper with the provided value and
+    /// maximum number of mantissa decimal places
+    pub fn new(value: impl Into<f64>, precision: u32) -> Self {
+        Self{
+            value: value.into(),
+            precision: precision.min(MAX_PRECISION),
+            names: SpecialFloatNames::DEFAULT,
+        }
+    }
+
+    /// Set the textual representations for NaN and infinite values
+    pub const fn names(mut self, names: SpecialFloatNames) -> Self {
+        self.names = names;
+        self
+    }
+
+    /// Split into a rounded mantissa and base 10 exponent
+    fn parts(&self) -> (Fractional<i128>, i32) {
+        let scale = 10i128.pow(self.precision);
+
+        let mut m = match self.value.is_sign_negative() {
+            true => -self.value,
+            false => self.value,
+        };
+
+        // Zero has no meaningful exponent
+        if m == 0.0 {
+            return (Fractional::new(0, scale), 0);
+        }
+
+        // Normalise to a single digit before the decimal point
+        let mut e = 0;
+        while m >= 10.0 {
+            m /= 10.0;
+            e += 1;
+        }
+        while m < 1.0 {
+            m *= 10.0;
+            e -= 1;
+        }
+
+        // Scale and round half away from zero
+        let v = m * scale as f64;
+        let mut i = v as i128;
+        if v - i as f64 >= 0.5 {
+            i += 1;
+        }
+
+        // Rounding may carry into another digit (9.99 -> 10.0)
+        if i >= 10 * scale {
+            i /= 10;
+            e += 1;
+        }
+
+        match self.value.is_sign_negative() {
+            true => (Fractional::new(-i, scale), e),
+            false => (Fractional::new(i, scale), e),
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [Scientific]
+impl EncodeStr for Scientific {
+    fn len(&self) -> usize {
+        if let Some(s) = self.names.special(self.value) {
+            return s.len();
+        }
+
+        let (m, e) = self.parts();
+
+        m.len() + 1 + e.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Write NaN and infinite values
+        if let Some(s) = self.names.special(self.value) {
+            return s.write(buff);
+        }
+
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let (m, e) = self.parts();
+
+        let mut n = m.write(buff)?;
+        n += 'e'.write(&mut buff[n..])?;
+        n += e.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::{Scientific, SpecialFloatNames};
+
+    #[test]
+    fn encode_scientific() {
+        let tests = &[
+            (Scientific::new(12300.0, 3), "1.23e4"),
+            (Scientific::new(0.00045, 3), "4.5e-4"),
+            (Scientific::new(0.0, 3), "0e0"),
+            (Scientific::new(-12300.0, 3), "-1.23e4"),
+            (Scientific::new(1.0, 3), "1e0"),
+            (Scientific::new(9.9996, 3), "1e1"),
+            (Scientific::new(123456.0, 2), "1.23e5"),
+            (Scientific::new(6.02e23, 4), "6.02e23"),
+            (Scientific::new(1.5e-12, 4), "1.5e-12"),
+            (Scientific::new(f64::NAN, 3), "NaN"),
+            (Scientific::new(f64::NEG_INFINITY, 3), "-inf"),
+            (Scientific::new(f64::NAN, 3).names(SpecialFloatNames::JSON), "null"),
+        ];
+
+        for (f, s) in tests {
+            assert_eq!(f.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(f.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! [HumanBytes] and [DataSize] helpers for displaying byte counts with unit prefixes
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{HumanBytes, DataSize}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let b = HumanBytes::new(1536);
+//! let s = b.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "1.5K");
+//! 
+//! let d = DataSize::new(1_572_864).decimal().decimals(2);
+//! let s = d.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "1.57 MB");
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::Fractional;
+
+/// Unit prefixes in ascending order
+const UNITS: [&str; 7] = ["", "K", "M", "G", "T", "P", "E"];
+
+/// Binary (IEC) units in ascending order
+const BINARY_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Decimal (SI) units in ascending order
+const DECIMAL_UNITS: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+
+/// Scale a byte count to the largest unit (of `base`) not exceeding it, rounding
+/// to the provided number of decimal places and returning the mantissa and unit index
+fn scale(bytes: u64, base: u64, decimals: u32) -> (Fractional<i128>, usize) {
+    let bytes = bytes as i128;
+    let base = base as i128;
+
+    // Find the largest unit not exceeding the byte count
+    let mut i = 0;
+    let mut divisor = 1;
+    while i + 1 < UNITS.len() && bytes >= divisor * base {
+        divisor *= base;
+        i += 1;
+    }
+
+    // Round to decimal places, moving up a unit if this carries
+    let mut f = Fractional::rounded(bytes, divisor, decimals).unwrap();
+    if i + 1 < UNITS.len() && f.value >= base * f.divisor {
+        divisor *= base;
+        i += 1;
+        f = Fractional::rounded(bytes, divisor, decimals).unwrap();
+    }
+
+    (f, i)
+}
+
+/// Helper for encoding byte counts using the largest fitting unit prefix
+/// with up to one decimal place (`1536` -> `1.5K`)
+pub struct HumanBytes {
+    bytes: u64,
+    base: u64,
+}
+
+impl HumanBytes {
+    /// Create a new byte count helper using binary (1024) units
+    pub const fn new(bytes: u64) -> Self {
+        Self{
+            bytes,
+            base: 1024,
+        }
+    }
+
+    /// Create a new byte count helper using decimal (1000) units
+    pub const fn decimal(bytes: u64) -> Self {
+        Self{
+            bytes,
+            base: 1000,
+        }
+    }
+
+    /// Select unit and compute mantissa
+    fn parts(&self) -> (Fractional<i128>, &'static str) {
+        let (f, i) = scale(self.bytes, self.base, 1);
+
+        // Trim trailing zero decimals
+        (Fractional::new(f.value, f.divisor), UNITS[i])
+    }
+}
+
+/// [EncodeStr] implementation for [HumanBytes]
+impl EncodeStr for HumanBytes {
+    fn len(&self) -> usize {
+        let (f, u) = self.parts();
+        f.len() + u.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let (f, u) = self.parts();
+
+        // Check buffer length
+        if buff.len() < f.len() + u.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write mantissa and unit
+        let mut n = f.write(buff)?;
+        n += u.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+/// Configurable helper for encoding byte counts with binary (`1.50 MiB`)
+/// or decimal (`1.57 MB`) units and a fixed number of decimal places.
+/// 
+/// Counts below the first unit are displayed as whole bytes (`512 B`).
+pub struct DataSize {
+    bytes: u64,
+    base: u64,
+    decimals: u32,
+    space: bool,
+}
+
+impl DataSize {
+    /// Create a new data size helper, defaulting to binary units with one
+    /// decimal place and a space between the value and unit
+    pub const fn new(bytes: u64) -> Self {
+        Self{
+            bytes,
+            base: 1024,
+            decimals: 1,
+            space: true,
+        }
+    }
+
+    /// Use binary (1024) units
+    pub const fn binary(mut self) -> Self {
+        self.base = 1024;
+        self
+    }
+
+    /// Use decimal (1000) units
+    pub const fn decimal(mut self) -> Self {
+        self.base = 1000;
+        self
+    }
+
+    /// Set the number of decimal places (up to 18), values are rounded half away from zero
+    pub const fn decimals(mut self, decimals: u32) -> Self {
+        self.decimals = if decimals > 18 { 18 } else { decimals };
+        self
+    }
+
+    /// Set whether a space is inserted between the value and unit
+    pub const fn space(mut self, space: bool) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Compute mantissa, unit, and unit separator
+    fn parts(&self) -> (Fractional<i128>, &'static str, &'static str) {
+        // Whole bytes are displayed without decimals
+        let (f, i) = match self.bytes < self.base {
+            true => (Fractional::new(self.bytes as i128, 1), 0),
+            false => scale(self.bytes, self.base, self.decimals),
+        };
+
+        let units = match self.base {
+            1000 => &DECIMAL_UNITS,
+            _ => &BINARY_UNITS,
+        };
+
+        let sep = match self.space {
+            true => " ",
+            false => "",
+        };
+
+        (f, units[i], sep)
+    }
+}
+
+/// [EncodeStr] implementation for [DataSize]
+impl EncodeStr for DataSize {
+    fn len(&self) -> usize {
+        let (f, u, s) = self.parts();
+        f.len() + s.len() + u.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let (f, u, s) = self.parts();
+
+        // Check buffer length
+        if buff.len() < f.len() + s.len() + u.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write mantissa, separator, and unit
+        let mut n = f.write(buff)?;
+        n += s.write(&mut buff[n..])?;
+        n += u.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::{HumanBytes, DataSize};
+
+    #[test]
+    fn encode_human_bytes() {
+        let tests = &[
+            (HumanBytes::new(0), "0"),
+            (HumanBytes::new(512), "512"),
+            (HumanBytes::new(1024), "1K"),
+            (HumanBytes::new(1536), "1.5K"),
+            (HumanBytes::new(2 * 1024 * 1024), "2M"),
+            (HumanBytes::new(1024 * 1024 - 1), "1M"),
+            (HumanBytes::new(5_500_000_000), "5.1G"),
+            (HumanBytes::new(u64::MAX), "16E"),
+            (HumanBytes::decimal(1000), "1K"),
+            (HumanBytes::decimal(1024), "1K"),
+            (HumanBytes::decimal(1536), "1.5K"),
+            (HumanBytes::decimal(3_000_000_000), "3G"),
+        ];
+
+        for (b, s) in tests {
+            assert_eq!(b.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(b.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_data_size() {
+        let tests = &[
+            (DataSize::new(0), "0 B"),
+            (DataSize::new(512), "512 B"),
+            (DataSize::new(1_572_864), "1.5 MiB"),
+            (DataSize::new(1_572_864).binary().decimals(2), "1.50 MiB"),
+            (DataSize::new(1_572_864).decimal().decimals(2), "1.57 MB"),
+            (DataSize::new(1_572_864).decimal().decimals(0), "2 MB"),
+            (DataSize::new(1_572_864).decimal().decimals(3).space(false), "1.573MB"),
+            (DataSize::new(1_048_575).decimals(2), "1.00 MiB"),
+            (DataSize::new(999).decimal(), "999 B"),
+            (DataSize::new(1000).decimal(), "1.0 kB"),
+        ];
+
+        for (d, s) in tests {
+            assert_eq!(d.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(d.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
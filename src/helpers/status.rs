@@ -0,0 +1,99 @@
+//! [Status] helper for displaying status codes with messages
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Status};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let s = Status::new(404u16, "Not Found");
+//! let s = s.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "[404] Not Found");
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Helper for encoding a bracketed status code followed by a message
+pub struct Status<'a, C: EncodeStr> {
+    code: C,
+    msg: &'a str,
+    open: char,
+    close: char,
+}
+
+impl <'a, C: EncodeStr> Status<'a, C> {
+    /// Create a new status with the provided code and message, using `[]` brackets
+    pub const fn new(code: C, msg: &'a str) -> Self {
+        Self::with_brackets(code, msg, '[', ']')
+    }
+
+    /// Create a new status with the provided code, message, and brackets
+    pub const fn with_brackets(code: C, msg: &'a str, open: char, close: char) -> Self {
+        Self{
+            code,
+            msg,
+            open,
+            close,
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [Status]
+impl <'a, C: EncodeStr> EncodeStr for Status<'a, C> {
+    fn len(&self) -> usize {
+        self.open.len() + self.code.len() + self.close.len() + 1 + self.msg.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write bracketed code
+        let mut n = self.open.write(buff)?;
+        n += self.code.write(&mut buff[n..])?;
+        n += self.close.write(&mut buff[n..])?;
+
+        // Write message
+        n += ' '.write(&mut buff[n..])?;
+        n += self.msg.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.code.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error, helpers::Bytes};
+    use super::Status;
+
+    #[test]
+    fn encode_status() {
+        let tests = &[
+            (Status::new(404u16, "Not Found"), "[404] Not Found"),
+            (Status::new(200u16, "OK"), "[200] OK"),
+            (Status::with_brackets(500u16, "Internal Error", '(', ')'), "(500) Internal Error"),
+        ];
+
+        for (v, s) in tests {
+            assert_eq!(v.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(v.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn status_invalid_utf8() {
+        let v = Status::new(Bytes(&[b'a', 0xff]), "Not Found");
+
+        let mut buff = [b'x'; 16];
+        assert_eq!(v.validate(), Err(Error::InvalidUtf8));
+        assert_eq!(v.write_str(&mut buff), Err(Error::InvalidUtf8));
+        assert_eq!(&buff, b"xxxxxxxxxxxxxxxx");
+    }
+}
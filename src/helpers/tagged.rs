@@ -0,0 +1,74 @@
+//! [Tagged] helper for displaying [Result]s with their variant
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::Tagged};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let r: Result<u8, &str> = Ok(5);
+//! assert_eq!(Tagged(r).write_str(&mut buff).unwrap(), "Ok(5)");
+//! 
+//! let r: Result<u8, &str> = Err("timeout");
+//! assert_eq!(Tagged(r).write_str(&mut buff).unwrap(), "Err(timeout)");
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Wrapper type for encoding [Result]s as `Ok(..)` or `Err(..)`,
+/// see the [Result] implementation of [EncodeStr] for bare values
+pub struct Tagged<T: EncodeStr, E: EncodeStr>(pub Result<T, E>);
+
+impl <T: EncodeStr, E: EncodeStr> Tagged<T, E> {
+    /// Fetch the variant prefix
+    fn prefix(&self) -> &'static str {
+        match self.0 {
+            Ok(_) => "Ok(",
+            Err(_) => "Err(",
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [Tagged]
+impl <T: EncodeStr, E: EncodeStr> EncodeStr for Tagged<T, E> {
+    fn len(&self) -> usize {
+        self.prefix().len() + self.0.len() + 1
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = self.prefix().write(buff)?;
+        n += self.0.write(&mut buff[n..])?;
+        n += ')'.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.0.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_tagged() {
+        let tests: &[(Result<u16, i32>, &str)] = &[
+            (Ok(5), "Ok(5)"),
+            (Ok(1234), "Ok(1234)"),
+            (Err(-12), "Err(-12)"),
+        ];
+
+        for (v, s) in tests {
+            let t = Tagged(*v);
+            let mut buff = [0u8; 32];
+
+            assert_eq!(t.len(), s.len());
+            assert_eq!(t.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
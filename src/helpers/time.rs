@@ -0,0 +1,422 @@
+//! Time helpers for displaying durations
+//! 
+//! ```
+//! # use core::time::Duration;
+//! # use emstr::{EncodeStr, helpers::{TimeMs, HmsTime, DurationFmt, Millis}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let t = TimeMs::new(83_456);
+//! let s = t.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "01:23.456");
+//! 
+//! let t = HmsTime::compact(3723);
+//! let s = t.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "1h2m3s");
+//! 
+//! let d = DurationFmt::millis(Duration::from_micros(1_500_250), 1);
+//! let s = d.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "1500.3ms");
+//! 
+//! let m = Millis::new(150_000);
+//! let s = m.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "2m30s");
+//! ```
+
+use core::time::Duration;
+
+use crate::{EncodeStr, Error};
+use super::{PadLeft, Fractional};
+
+/// Helper for encoding a millisecond count as a compact `mm:ss.mmm` timestamp,
+/// or `hh:mm:ss.mmm` where hours are enabled
+pub struct TimeMs {
+    millis: u64,
+    hours: bool,
+}
+
+impl TimeMs {
+    /// Create a new `mm:ss.mmm` timestamp, minutes are not wrapped so
+    /// times over an hour display as `75:00.000`
+    pub const fn new(millis: u64) -> Self {
+        Self{
+            millis,
+            hours: false,
+        }
+    }
+
+    /// Create a new `hh:mm:ss.mmm` timestamp
+    pub const fn with_hours(millis: u64) -> Self {
+        Self{
+            millis,
+            hours: true,
+        }
+    }
+
+    /// Split into zero-padded (hours, minutes, seconds, milliseconds) fields
+    fn fields(&self) -> (Option<PadLeft<u64>>, PadLeft<u64>, PadLeft<u64>, PadLeft<u64>) {
+        let minutes = self.millis / 60_000;
+
+        let (h, m) = match self.hours {
+            true => (Some(PadLeft::new(minutes / 60, 2, '0')), minutes % 60),
+            false => (None, minutes),
+        };
+
+        (
+            h,
+            PadLeft::new(m, 2, '0'),
+            PadLeft::new(self.millis / 1000 % 60, 2, '0'),
+            PadLeft::new(self.millis % 1000, 3, '0'),
+        )
+    }
+}
+
+/// [EncodeStr] implementation for [TimeMs]
+impl EncodeStr for TimeMs {
+    fn len(&self) -> usize {
+        let (h, m, s, ms) = self.fields();
+
+        let mut n = m.len() + 1 + s.len() + 1 + ms.len();
+        if let Some(h) = h {
+            n += h.len() + 1;
+        }
+
+        n
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let (h, m, s, ms) = self.fields();
+        let mut n = 0;
+
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write optional hours
+        if let Some(h) = h {
+            n += h.write(&mut buff[n..])?;
+            n += ':'.write(&mut buff[n..])?;
+        }
+
+        // Write minutes, seconds and milliseconds
+        n += m.write(&mut buff[n..])?;
+        n += ':'.write(&mut buff[n..])?;
+        n += s.write(&mut buff[n..])?;
+        n += '.'.write(&mut buff[n..])?;
+        n += ms.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+/// Helper for encoding a number of seconds as hours, minutes and seconds,
+/// either compact (`1h2m3s`) or fixed (`01:02:03`)
+pub struct HmsTime {
+    secs: u64,
+    compact: bool,
+}
+
+impl HmsTime {
+    /// Create a new compact `1h2m3s` time, omitting leading zero units
+    pub const fn compact(secs: u64) -> Self {
+        Self{
+            secs,
+            compact: true,
+        }
+    }
+
+    /// Create a new fixed `hh:mm:ss` time, hours widen beyond two digits as required
+    pub const fn fixed(secs: u64) -> Self {
+        Self{
+            secs,
+            compact: false,
+        }
+    }
+
+    /// Split into (hours, minutes, seconds) fields
+    fn fields(&self) -> (u64, u64, u64) {
+        (self.secs / 3600, self.secs / 60 % 60, self.secs % 60)
+    }
+}
+
+/// [EncodeStr] implementation for [HmsTime]
+impl EncodeStr for HmsTime {
+    fn len(&self) -> usize {
+        let (h, m, s) = self.fields();
+
+        if !self.compact {
+            return PadLeft::new(h, 2, '0').len() + 6;
+        }
+
+        match (h, m) {
+            (0, 0) => s.len() + 1,
+            (0, _) => m.len() + 1 + s.len() + 1,
+            _ => h.len() + 1 + m.len() + 1 + s.len() + 1,
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let (h, m, s) = self.fields();
+        let mut n = 0;
+
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write fixed hh:mm:ss
+        if !self.compact {
+            n += PadLeft::new(h, 2, '0').write(buff)?;
+            n += ':'.write(&mut buff[n..])?;
+            n += PadLeft::new(m, 2, '0').write(&mut buff[n..])?;
+            n += ':'.write(&mut buff[n..])?;
+            n += PadLeft::new(s, 2, '0').write(&mut buff[n..])?;
+
+            return Ok(n)
+        }
+
+        // Write compact units, skipping leading zeroes
+        if h > 0 {
+            n += h.write(&mut buff[n..])?;
+            n += 'h'.write(&mut buff[n..])?;
+        }
+        if h > 0 || m > 0 {
+            n += m.write(&mut buff[n..])?;
+            n += 'm'.write(&mut buff[n..])?;
+        }
+        n += s.write(&mut buff[n..])?;
+        n += 's'.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+/// Helper for encoding [Duration]s in seconds (`1.500s`) or milliseconds (`1500ms`)
+/// with a fixed number of decimal places, rounding half away from zero.
+/// 
+/// Durations are resolved to the nanosecond, decimal places beyond this are zero-filled
+pub struct DurationFmt {
+    duration: Duration,
+    divisor: i128,
+    unit: &'static str,
+    decimals: u32,
+}
+
+impl DurationFmt {
+    /// Create a new duration formatter displaying seconds with the provided decimal places
+    pub const fn secs(duration: Duration, decimals: u32) -> Self {
+        Self{
+            duration,
+            divisor: 1_000_000_000,
+            unit: "s",
+            decimals,
+        }
+    }
+
+    /// Create a new duration formatter displaying milliseconds with the provided decimal places
+    pub const fn millis(duration: Duration, decimals: u32) -> Self {
+        Self{
+            duration,
+            divisor: 1_000_000,
+            unit: "ms",
+            decimals,
+        }
+    }
+
+    /// Scale the duration to the configured unit, limited to nanosecond resolution
+    fn value(&self) -> Fractional<i128> {
+        // Duration::MAX is ~1.8e28ns, rescaling by at most 1e9 cannot overflow i128
+        let nanos = self.duration.as_nanos() as i128;
+        Fractional::rounded(nanos, self.divisor, self.decimals.min(self.divisor.ilog10())).unwrap()
+    }
+
+    /// Zero-filled decimal places beyond nanosecond resolution
+    fn fill(&self) -> usize {
+        self.decimals.saturating_sub(self.divisor.ilog10()) as usize
+    }
+}
+
+/// [EncodeStr] implementation for [DurationFmt]
+impl EncodeStr for DurationFmt {
+    fn len(&self) -> usize {
+        self.value().len() + self.fill() + self.unit.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let v = self.value();
+        let fill = self.fill();
+
+        // Check buffer length
+        if buff.len() < v.len() + fill + self.unit.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write value, zero fill and unit
+        let mut n = v.write(buff)?;
+        buff[n..][..fill].fill(b'0');
+        n += fill;
+        n += self.unit.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+/// Helper for encoding a millisecond count compactly with automatic unit
+/// selection, truncating to the displayed precision:
+/// 
+/// - `<1ms` for zero
+/// - `250ms` below one second
+/// - `1.50s` below one minute
+/// - `2m30s` below one hour
+/// - `1h5m` otherwise
+pub struct Millis(pub u64);
+
+impl Millis {
+    /// Create a new auto-scaled millisecond duration
+    pub const fn new(millis: u64) -> Self {
+        Self(millis)
+    }
+}
+
+/// [EncodeStr] implementation for [Millis]
+impl EncodeStr for Millis {
+    fn len(&self) -> usize {
+        let ms = self.0;
+
+        match ms {
+            0 => "<1ms".len(),
+            1..=999 => ms.len() + 2,
+            1_000..=59_999 => Fractional::fixed(ms as i64 / 10, 100).len() + 1,
+            60_000..=3_599_999 => HmsTime::compact(ms / 1000).len(),
+            _ => (ms / 3_600_000).len() + 1 + (ms / 60_000 % 60).len() + 1,
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let ms = self.0;
+
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let n = match ms {
+            0 => "<1ms".write(buff)?,
+            1..=999 => crate::write!(buff[..], ms, "ms")?,
+            1_000..=59_999 => crate::write!(buff[..], Fractional::fixed(ms as i64 / 10, 100), 's')?,
+            60_000..=3_599_999 => HmsTime::compact(ms / 1000).write(buff)?,
+            _ => crate::write!(buff[..], ms / 3_600_000, 'h', ms / 60_000 % 60, 'm')?,
+        };
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use crate::EncodeStr;
+    use super::{TimeMs, HmsTime, DurationFmt, Millis};
+
+    #[test]
+    fn encode_time_ms() {
+        let tests = &[
+            (TimeMs::new(0), "00:00.000"),
+            (TimeMs::new(5_007), "00:05.007"),
+            (TimeMs::new(83_456), "01:23.456"),
+            (TimeMs::new(754_010), "12:34.010"),
+            (TimeMs::new(4_500_000), "75:00.000"),
+            (TimeMs::with_hours(83_456), "00:01:23.456"),
+            (TimeMs::with_hours(4_500_000), "01:15:00.000"),
+        ];
+
+        for (t, s) in tests {
+            assert_eq!(t.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(t.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_hms_time() {
+        let tests = &[
+            (HmsTime::compact(0), "0s"),
+            (HmsTime::compact(45), "45s"),
+            (HmsTime::compact(60), "1m0s"),
+            (HmsTime::compact(3723), "1h2m3s"),
+            (HmsTime::compact(3600), "1h0m0s"),
+            (HmsTime::compact(45296), "12h34m56s"),
+            (HmsTime::fixed(0), "00:00:00"),
+            (HmsTime::fixed(45), "00:00:45"),
+            (HmsTime::fixed(3723), "01:02:03"),
+            (HmsTime::fixed(45296), "12:34:56"),
+            (HmsTime::fixed(360_000), "100:00:00"),
+        ];
+
+        for (t, s) in tests {
+            assert_eq!(t.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(t.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_duration_fmt() {
+        let tests = &[
+            (DurationFmt::secs(Duration::from_secs(2), 3), "2.000s"),
+            (DurationFmt::secs(Duration::from_millis(250), 3), "0.250s"),
+            (DurationFmt::secs(Duration::from_millis(1500), 3), "1.500s"),
+            (DurationFmt::secs(Duration::from_millis(1500), 0), "2s"),
+            (DurationFmt::secs(Duration::from_micros(12_345_678), 2), "12.35s"),
+            (DurationFmt::millis(Duration::from_secs(2), 0), "2000ms"),
+            (DurationFmt::millis(Duration::from_millis(250), 0), "250ms"),
+            (DurationFmt::millis(Duration::from_micros(1_500_250), 2), "1500.25ms"),
+            (DurationFmt::secs(Duration::from_nanos(1_500_000_001), 12), "1.500000001000s"),
+            (DurationFmt::millis(Duration::from_nanos(1_500_001), 8), "1.50000100ms"),
+            (DurationFmt::secs(Duration::MAX, 9), "18446744073709551615.999999999s"),
+            (DurationFmt::millis(Duration::MAX, 40), "18446744073709551615999.9999990000000000000000000000000000000000ms"),
+        ];
+
+        for (d, s) in tests {
+            assert_eq!(d.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 80];
+            assert_eq!(d.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn encode_millis() {
+        let tests = &[
+            (Millis::new(0), "<1ms"),
+            (Millis::new(1), "1ms"),
+            (Millis::new(250), "250ms"),
+            (Millis::new(999), "999ms"),
+            (Millis::new(1_000), "1.00s"),
+            (Millis::new(1_500), "1.50s"),
+            (Millis::new(1_999), "1.99s"),
+            (Millis::new(12_345), "12.34s"),
+            (Millis::new(59_999), "59.99s"),
+            (Millis::new(60_000), "1m0s"),
+            (Millis::new(150_000), "2m30s"),
+            (Millis::new(3_599_999), "59m59s"),
+            (Millis::new(3_600_000), "1h0m"),
+            (Millis::new(3_900_000), "1h5m"),
+            (Millis::new(u64::MAX), "5124095576030h25m"),
+        ];
+
+        for (m, s) in tests {
+            assert_eq!(m.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(m.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
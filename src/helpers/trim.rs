@@ -0,0 +1,180 @@
+//! [TrimEnd] helper for trimming trailing characters from encoded values
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::TrimEnd};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let t = TrimEnd::new(("a,", "b,", "c,"), ',');
+//! assert_eq!(t.write_str(&mut buff).unwrap(), "a,b,c");
+//! 
+//! let t = TrimEnd::any("value, \n", b", \n");
+//! assert_eq!(t.write_str(&mut buff).unwrap(), "value");
+//! ```
+
+use crate::{EncodeStr, Error};
+
+/// Scratch length for chunked encoding when computing trimmed lengths
+const SCRATCH_LEN: usize = 128;
+
+/// Helper for encoding an inner value with trailing characters removed.
+/// 
+/// As trimming requires the encoded content, `write()` encodes the inner
+/// value to the output buffer (requiring the full untrimmed length) then
+/// re-scans from the end. `len()` streams the inner value via
+/// [EncodeStr::write_chunks] tracking the trailing run of trimmed characters,
+/// so is exact for values of any length where each chunk fits in 128 bytes.
+pub struct TrimEnd<'a, E: EncodeStr> {
+    inner: E,
+    pattern: Pattern<'a>,
+}
+
+/// Characters to be trimmed
+enum Pattern<'a> {
+    /// A single (potentially multi-byte) character
+    Char(char),
+    /// Any of a set of bytes
+    Bytes(&'a [u8]),
+}
+
+impl <E: EncodeStr> TrimEnd<'static, E> {
+    /// Create a new wrapper trimming all trailing instances of `c`
+    pub const fn new(inner: E, c: char) -> Self {
+        Self{
+            inner,
+            pattern: Pattern::Char(c),
+        }
+    }
+}
+
+impl <'a, E: EncodeStr> TrimEnd<'a, E> {
+    /// Create a new wrapper trimming any trailing bytes in `set`,
+    /// intended for ASCII characters
+    pub const fn any(inner: E, set: &'a [u8]) -> Self {
+        Self{
+            inner,
+            pattern: Pattern::Bytes(set),
+        }
+    }
+
+    /// Compute the trimmed length of encoded data
+    fn trimmed(&self, data: &[u8]) -> usize {
+        let mut n = data.len();
+
+        match &self.pattern {
+            Pattern::Char(c) => {
+                let mut b = [0u8; 4];
+                let c = c.encode_utf8(&mut b).as_bytes();
+
+                while data[..n].ends_with(c) {
+                    n -= c.len();
+                }
+            },
+            Pattern::Bytes(set) => {
+                while n > 0 && set.contains(&data[n - 1]) {
+                    n -= 1;
+                }
+            },
+        }
+
+        n
+    }
+
+    /// Update the trailing trimmable run (in bytes) with the next encoded chunk
+    fn trailing(&self, chunk: &str, run: usize) -> usize {
+        match self.trimmed(chunk.as_bytes()) {
+            // Whole chunk trimmable, extend the existing run
+            0 => run + chunk.len(),
+            n => chunk.len() - n,
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [TrimEnd]
+impl <'a, E: EncodeStr> EncodeStr for TrimEnd<'a, E> {
+    fn len(&self) -> usize {
+        let (mut total, mut run) = (0, 0);
+
+        // Stream encoded chunks, chunks are whole `str`s so character
+        // patterns cannot be split across chunks
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let r = self.inner.write_chunks(&mut scratch, |chunk| {
+            total += chunk.len();
+            run = self.trailing(chunk, run);
+            Ok(())
+        });
+
+        match r {
+            Ok(()) => total - run,
+            Err(_) => self.inner.len(),
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Encode untrimmed value to output buffer
+        let n = self.inner.write(buff)?;
+
+        // Re-scan to trim trailing characters
+        Ok(self.trimmed(&buff[..n]))
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.inner.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_trim_end() {
+        let tests = &[
+            (TrimEnd::new("a,b,c,", ','), "a,b,c"),
+            (TrimEnd::new("a,b,c,,,", ','), "a,b,c"),
+            (TrimEnd::new("a,b,c", ','), "a,b,c"),
+            (TrimEnd::new(",,,", ','), ""),
+            (TrimEnd::new("", ','), ""),
+            (TrimEnd::new("1.2300", '0'), "1.23"),
+            (TrimEnd::new("a → b → ", ' '), "a → b →"),
+            (TrimEnd::new("a → b → ", '→'), "a → b → "),
+            (TrimEnd::any("a, b, ", b", "), "a, b"),
+        ];
+
+        for (t, s) in tests {
+            assert_eq!(t.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(t.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn trim_end_multibyte() {
+        let mut buff = [0u8; 32];
+
+        let t = TrimEnd::new("ab→→", '→');
+        assert_eq!(t.len(), 2);
+        assert_eq!(t.write_str(&mut buff), Ok("ab"));
+    }
+
+    #[test]
+    fn trim_end_long() {
+        let mut buff = [0u8; 512];
+
+        // Inner values larger than the scratch buffer
+        let s = "a,".repeat(80);
+        let t = TrimEnd::new(s.as_str(), ',');
+        assert_eq!(t.len(), 159);
+        assert_eq!(t.write_str(&mut buff).map(str::len), Ok(159));
+
+        // Trailing run spanning multiple chunks
+        let pad = ",".repeat(200);
+        let t = TrimEnd::new(("abc", pad.as_str(), pad.as_str()), ',');
+        assert_eq!(t.len(), 3);
+        assert_eq!(t.write_str(&mut buff), Ok("abc"));
+
+        let t = TrimEnd::new((s.as_str(), 1234u32, pad.as_str()), ',');
+        assert_eq!(t.len(), 164);
+        assert_eq!(t.write_str(&mut buff).map(str::len), Ok(164));
+    }
+}
@@ -0,0 +1,121 @@
+//! [Uuid] and [BleUuid] helpers for displaying byte arrays as UUIDs
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Uuid, BleUuid}};
+//! # let mut buff = [0u8; 40];
+//! 
+//! let u = Uuid([0x12, 0x3e, 0x45, 0x67, 0xe8, 0x9b, 0x12, 0xd3, 0xa4, 0x56, 0x42, 0x66, 0x14, 0x17, 0x40, 0x00]);
+//! let s = u.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "123e4567-e89b-12d3-a456-426614174000");
+//! 
+//! let b = BleUuid(&[0x18, 0x0a]);
+//! let s = b.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "180a");
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::Hex;
+
+/// Byte offsets at which UUID hyphens are inserted
+const HYPHENS: [usize; 4] = [4, 6, 8, 10];
+
+/// Wrapper type for encoding byte arrays as hyphenated UUID strings
+/// (`xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` for 16 byte inputs)
+pub struct Uuid<B: AsRef<[u8]>>(pub B);
+
+/// [EncodeStr] implementation to write bytes as a hyphenated UUID
+impl <B: AsRef<[u8]>> EncodeStr for Uuid<B> {
+    fn len(&self) -> usize {
+        let b = self.0.as_ref();
+        let h = HYPHENS.iter().filter(|i| **i < b.len()).count();
+
+        b.len() * 2 + h
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let b = self.0.as_ref();
+
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write hex segments separated by hyphens
+        let mut n = 0;
+        let mut start = 0;
+        for i in HYPHENS.iter().filter(|i| **i < b.len()) {
+            n += Hex(&b[start..*i]).write(&mut buff[n..])?;
+            n += '-'.write(&mut buff[n..])?;
+            start = *i;
+        }
+        n += Hex(&b[start..]).write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+/// Wrapper type for encoding Bluetooth UUIDs, rendering 2 and 4 byte
+/// inputs as short hex UUIDs (`180a`, `0000180a`) and 16 byte inputs as
+/// full hyphenated UUIDs. Other lengths are encoded as plain hex.
+/// 
+/// Bytes are rendered in the order provided, so little-endian values
+/// from the air should be reversed prior to encoding.
+pub struct BleUuid<B: AsRef<[u8]>>(pub B);
+
+/// [EncodeStr] implementation to write bytes as a Bluetooth UUID
+impl <B: AsRef<[u8]>> EncodeStr for BleUuid<B> {
+    fn len(&self) -> usize {
+        let b = self.0.as_ref();
+        match b.len() {
+            16 => Uuid(b).len(),
+            _ => Hex(b).len(),
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let b = self.0.as_ref();
+        match b.len() {
+            16 => Uuid(b).write(buff),
+            _ => Hex(b).write(buff),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+    use super::{Uuid, BleUuid};
+
+    const FULL: [u8; 16] = [0x00, 0x00, 0x18, 0x0a, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0x80, 0x5f, 0x9b, 0x34, 0xfb];
+
+    #[test]
+    fn encode_uuid() {
+        let u = Uuid(FULL);
+
+        let mut buff = [0u8; 40];
+        let s = u.write_str(&mut buff).unwrap();
+
+        assert_eq!(s, "0000180a-0000-1000-8000-00805f9b34fb");
+        assert_eq!(u.len(), s.len());
+    }
+
+    #[test]
+    fn encode_ble_uuid() {
+        let tests: &[(&[u8], &str)] = &[
+            (&[0x18, 0x0a], "180a"),
+            (&[0x00, 0x00, 0x18, 0x0a], "0000180a"),
+            (&FULL, "0000180a-0000-1000-8000-00805f9b34fb"),
+        ];
+
+        for (b, s) in tests {
+            let u = BleUuid(b);
+
+            assert_eq!(u.len(), s.len());
+
+            let mut buff = [0u8; 40];
+            assert_eq!(u.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! [ValueUnit] helper for displaying right-aligned values with a trailing unit
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::ValueUnit};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let v = ValueUnit::new(42u32, 4, " kg");
+//! let s = v.write_str(&mut buff).unwrap();
+//! 
+//! assert_eq!(s, "  42 kg");
+//! ```
+
+use crate::{EncodeStr, Error};
+use super::PadLeft;
+
+/// Helper for encoding a value right-aligned to a fixed width, followed by a unit
+pub struct ValueUnit<E: EncodeStr, U: EncodeStr> {
+    value: PadLeft<E>,
+    unit: U,
+}
+
+impl <E: EncodeStr, U: EncodeStr> ValueUnit<E, U> {
+    /// Create a new value / unit wrapper, padding the value to `width` characters
+    pub const fn new(value: E, width: usize, unit: U) -> Self {
+        Self{
+            value: PadLeft::new(value, width, ' '),
+            unit,
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [ValueUnit]
+impl <E: EncodeStr, U: EncodeStr> EncodeStr for ValueUnit<E, U> {
+    fn len(&self) -> usize {
+        self.value.len() + self.unit.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write padded value
+        let mut n = self.value.write(buff)?;
+
+        // Write unit
+        n += self.unit.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.value.validate()?;
+        self.unit.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error, helpers::Bytes};
+    use super::ValueUnit;
+
+    #[test]
+    fn encode_value_unit() {
+        let tests = &[
+            (42u32, "  42 kg"),
+            (1234u32, "1234 kg"),
+            (123456u32, "123456 kg"),
+        ];
+
+        for (v, s) in tests {
+            let u = ValueUnit::new(*v, 4, " kg");
+
+            assert_eq!(u.len(), s.len());
+
+            let mut buff = [0u8; 32];
+            assert_eq!(u.write_str(&mut buff), Ok(*s));
+        }
+    }
+
+    #[test]
+    fn value_unit_alignment() {
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+
+        let a = ValueUnit::new(7u8, 4, " kg").write_str(&mut a).unwrap();
+        let b = ValueUnit::new(1000u16, 4, " kg").write_str(&mut b).unwrap();
+
+        assert_eq!(a.find("kg"), b.find("kg"));
+    }
+
+    #[test]
+    fn value_unit_invalid_utf8() {
+        let v = ValueUnit::new(Bytes(&[b'a', 0xff]), 4, " kg");
+
+        let mut buff = [b'x'; 16];
+        assert_eq!(v.validate(), Err(Error::InvalidUtf8));
+        assert_eq!(v.write_str(&mut buff), Err(Error::InvalidUtf8));
+        assert_eq!(&buff, b"xxxxxxxxxxxxxxxx");
+    }
+}
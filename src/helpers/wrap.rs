@@ -0,0 +1,181 @@
+//! [Wrap], [Prefix], and [Suffix] helpers for surrounding encodable types with literals
+//! 
+//! ```
+//! # use emstr::{EncodeStr, helpers::{Hex, Prefix, Suffix, Wrap}};
+//! # let mut buff = [0u8; 32];
+//! 
+//! let p = Prefix::new("0x", Hex(&[0x12, 0x34, 0xff]));
+//! assert_eq!(p.write_str(&mut buff).unwrap(), "0x1234ff");
+//! 
+//! let s = Suffix::new(250u32, "ms");
+//! assert_eq!(s.write_str(&mut buff).unwrap(), "250ms");
+//! 
+//! let w = Wrap::new("(", 42u8, ")");
+//! assert_eq!(w.write_str(&mut buff).unwrap(), "(42)");
+//! ```
+
+use crate::{EncodeStr, Error, WriteMode};
+
+/// Helper for encoding a value between a prefix and suffix
+pub struct Wrap<'a, E: EncodeStr> {
+    prefix: &'a str,
+    inner: E,
+    suffix: &'a str,
+}
+
+impl <'a, E: EncodeStr> Wrap<'a, E> {
+    /// Create a new wrapper with the provided prefix, inner value, and suffix
+    pub const fn new(prefix: &'a str, inner: E, suffix: &'a str) -> Self {
+        Self{
+            prefix,
+            inner,
+            suffix,
+        }
+    }
+}
+
+/// [EncodeStr] implementation for [Wrap]
+impl <'a, E: EncodeStr> EncodeStr for Wrap<'a, E> {
+    fn len(&self) -> usize {
+        self.prefix.len() + self.inner.len() + self.suffix.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = self.prefix.write(buff)?;
+        n += self.inner.write(&mut buff[n..])?;
+        n += self.suffix.write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        if mode == WriteMode::Strict || self.len() <= buff.len() {
+            return self.write(buff);
+        }
+
+        // Truncate prefix, inner value, then suffix until the buffer is full
+        let mut n = 0;
+        if crate::write_part_truncated(&self.prefix, buff, &mut n)?
+                && crate::write_part_truncated(&self.inner, buff, &mut n)? {
+            crate::write_part_truncated(&self.suffix, buff, &mut n)?;
+        }
+
+        Ok(n)
+    }
+
+    fn write_chunks(&self, scratch: &mut [u8], mut f: impl FnMut(&str) -> Result<(), Error>) -> Result<(), Error> {
+        f(self.prefix)?;
+        self.inner.write_chunks(scratch, &mut f)?;
+        f(self.suffix)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.inner.validate()
+    }
+}
+
+/// Helper for encoding a value with a prefix (`0x1234`)
+pub struct Prefix<'a, E: EncodeStr>(Wrap<'a, E>);
+
+impl <'a, E: EncodeStr> Prefix<'a, E> {
+    /// Create a new prefix wrapper
+    pub const fn new(prefix: &'a str, inner: E) -> Self {
+        Self(Wrap::new(prefix, inner, ""))
+    }
+}
+
+/// [EncodeStr] implementation for [Prefix]
+impl <'a, E: EncodeStr> EncodeStr for Prefix<'a, E> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        self.0.write(buff)
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        self.0.write_with(buff, mode)
+    }
+
+    fn write_chunks(&self, scratch: &mut [u8], f: impl FnMut(&str) -> Result<(), Error>) -> Result<(), Error> {
+        self.0.write_chunks(scratch, f)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.0.validate()
+    }
+}
+
+/// Helper for encoding a value with a suffix (`250ms`)
+pub struct Suffix<'a, E: EncodeStr>(Wrap<'a, E>);
+
+impl <'a, E: EncodeStr> Suffix<'a, E> {
+    /// Create a new suffix wrapper
+    pub const fn new(inner: E, suffix: &'a str) -> Self {
+        Self(Wrap::new("", inner, suffix))
+    }
+}
+
+/// [EncodeStr] implementation for [Suffix]
+impl <'a, E: EncodeStr> EncodeStr for Suffix<'a, E> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        self.0.write(buff)
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        self.0.write_with(buff, mode)
+    }
+
+    fn write_chunks(&self, scratch: &mut [u8], f: impl FnMut(&str) -> Result<(), Error>) -> Result<(), Error> {
+        self.0.write_chunks(scratch, f)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.0.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error, helpers::Hex};
+    use super::{Wrap, Prefix, Suffix};
+
+    #[test]
+    fn encode_prefix() {
+        let p = Prefix::new("0x", Hex([0x12, 0x34, 0xff]));
+
+        let mut buff = [0u8; 32];
+        assert_eq!(p.write_str(&mut buff), Ok("0x1234ff"));
+        assert_eq!(p.len(), 8);
+    }
+
+    #[test]
+    fn encode_suffix() {
+        let s = Suffix::new(1500u32, "ms");
+
+        let mut buff = [0u8; 32];
+        assert_eq!(s.write_str(&mut buff), Ok("1500ms"));
+        assert_eq!(s.len(), 6);
+    }
+
+    #[test]
+    fn encode_wrap() {
+        let w = Wrap::new("[", "abc", "]");
+
+        let mut buff = [0u8; 32];
+        assert_eq!(w.write_str(&mut buff), Ok("[abc]"));
+
+        let mut buff = [0u8; 4];
+        assert_eq!(w.write(&mut buff), Err(Error::BufferLength));
+    }
+}
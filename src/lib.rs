@@ -18,16 +18,49 @@
 //! ```
 //! 
 
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::mem::MaybeUninit;
 
 mod error;
 pub use error::Error;
 
 mod types;
 
+mod buf;
+pub use buf::{StrBuf, BufWriter, RingWriter};
+
 pub mod helpers;
 
+pub mod const_len;
+
+#[cfg(feature = "defmt")]
+mod log;
+#[cfg(feature = "defmt")]
+pub use log::DefmtStr;
+
+/// Overflow policy for [EncodeStr::write_with]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WriteMode {
+    /// Fail with [Error::BufferLength] where the output does not fit the buffer
+    Strict,
+    /// Truncate output to fit the buffer (at a character boundary)
+    Truncate,
+}
+
+/// Scratch length for the default [WriteMode::Truncate] implementation
+const TRUNCATE_SCRATCH_LEN: usize = 128;
+
 /// [EncodeStr] implemented for string writable types
+#[allow(clippy::len_without_is_empty)]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `EncodeStr`",
+    label = "cannot be encoded with emstr",
+    note = "see `emstr::helpers` for wrappers, or `emstr::helpers::FromDisplay` for `core::fmt::Display` types",
+)]
 pub trait EncodeStr {
     /// Fetch the encoded length of the object
     fn len(&self) -> usize;
@@ -36,13 +69,201 @@ pub trait EncodeStr {
     /// of characters written to the buffer
     fn write(&self, buff: &mut [u8]) -> Result<usize, Error>;
 
+    /// Check the encoded output will be valid UTF-8 prior to writing,
+    /// overridden by encoders of arbitrary bytes
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Encode to the provided buffer without checking the buffer length,
+    /// for hot paths where the buffer has already been sized (eg. via [len!]),
+    /// returning the number of characters written.
+    /// 
+    /// The default implementation calls [EncodeStr::write] and panics on
+    /// failure, so should only be used with encoders that cannot fail other
+    /// than on buffer length. Integer, hex and string encoders are infallible
+    /// and override this to skip bounds checks.
+    /// 
+    /// # Safety
+    /// `buff` must be at least `len()` bytes long
+    /// 
+    /// # Panics
+    /// Where the default implementation is used and [EncodeStr::write] fails
+    /// (eg. with [Error::Overflow] or [Error::TooWide])
+    unsafe fn write_unchecked(&self, buff: &mut [u8]) -> usize {
+        match self.write(buff) {
+            Ok(n) => n,
+            Err(e) => panic!("EncodeStr::write_unchecked failed: {:?}", e),
+        }
+    }
+
+    /// Encode to the provided buffer with the specified [WriteMode] overflow
+    /// policy, returning the number of characters written
+    /// 
+    /// [WriteMode::Strict] delegates to [EncodeStr::write]. The default
+    /// [WriteMode::Truncate] implementation encodes over-length values into a
+    /// 128 byte scratch buffer and copies the prefix that fits (at a character
+    /// boundary), encoders able to truncate in place or in chunks override this.
+    /// 
+    /// # Errors
+    /// 
+    /// Using the default implementation, values with a `len()` over 128 bytes
+    /// that do not fit `buff` return [Error::BufferLength] in either mode.
+    /// Built-in composites (tuples, [Join](helpers::Join), [Wrap](helpers::Wrap),
+    /// [Pad](helpers::Pad)) and [Hex](helpers::Hex) truncate values of any length.
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        if mode == WriteMode::Strict || self.len() <= buff.len() {
+            return self.write(buff);
+        }
+
+        let mut scratch = [0u8; TRUNCATE_SCRATCH_LEN];
+        let n = self.write(&mut scratch)?;
+
+        let m = floor_char_boundary(&scratch[..n], buff.len());
+        buff[..m].copy_from_slice(&scratch[..m]);
+
+        Ok(m)
+    }
+
     /// Helper to encode to a borrowed string
+    /// 
+    /// In debug builds this asserts the number of bytes written matches `len()`,
+    /// catching encoders that under or over-estimate their length.
     fn write_str<'a>(&self, buff: &'a mut [u8]) -> Result<&'a str, Error> {
+        // Check content prior to writing
+        self.validate()?;
         // Encode to buffer
         let n = self.write(buff)?;
+        // Check encoded length matches len()
+        debug_assert_len(self, n);
         // Attempt string conversion
         core::str::from_utf8(&buff[..n]).map_err(|_| Error::InvalidUtf8)
     }
+
+    /// Helper to encode to a new fixed size array, returning the array
+    /// and the number of characters written
+    fn to_array<const N: usize>(&self) -> Result<([u8; N], usize), Error> where Self: Sized {
+        let mut buff = [0u8; N];
+        let n = self.write(&mut buff)?;
+        debug_assert_len(self, n);
+        Ok((buff, n))
+    }
+
+    /// Encode in chunks via the provided `scratch` buffer, calling `f` with each
+    /// encoded chunk, for streaming output larger than any fixed buffer.
+    /// 
+    /// The default implementation encodes the whole value in a single chunk,
+    /// requiring `scratch` to be at least `len()` bytes.
+    fn write_chunks(&self, scratch: &mut [u8], mut f: impl FnMut(&str) -> Result<(), Error>) -> Result<(), Error> where Self: Sized {
+        f(self.write_str(scratch)?)
+    }
+
+    /// Encode to an uninitialised buffer, returning the number of characters
+    /// written (and initialised) at the start of the buffer.
+    /// 
+    /// Only the `len()` bytes of output are initialised, avoiding zeroing
+    /// the whole buffer prior to encoding.
+    fn write_uninit(&self, buff: &mut [MaybeUninit<u8>]) -> Result<usize, Error> {
+        let n = self.len();
+
+        // Check buffer length
+        if buff.len() < n {
+            return Err(Error::BufferLength)
+        }
+
+        // Initialise output region
+        let out = &mut buff[..n];
+        for b in out.iter_mut() {
+            b.write(0);
+        }
+
+        // SAFETY: all `n` bytes of `out` were initialised above, and
+        // `MaybeUninit<u8>` has the same layout as `u8`
+        let out = unsafe { &mut *(out as *mut [MaybeUninit<u8>] as *mut [u8]) };
+
+        let w = self.write(out)?;
+        debug_assert_len(self, w);
+        Ok(w)
+    }
+
+    /// Helper to encode to a newly allocated [String](alloc::string::String)
+    #[cfg(feature = "alloc")]
+    fn encode_to_string(&self) -> Result<alloc::string::String, Error> {
+        let mut s = alloc::string::String::new();
+        self.append_to(&mut s)?;
+        Ok(s)
+    }
+
+    /// Helper to append to an existing [String](alloc::string::String),
+    /// leaving the string unchanged on failure
+    #[cfg(feature = "alloc")]
+    fn append_to(&self, s: &mut alloc::string::String) -> Result<(), Error> {
+        // Encode to a temporary sized via len()
+        let mut buff = alloc::vec![0u8; self.len()];
+        let v = self.write_str(&mut buff)?;
+
+        s.push_str(v);
+
+        Ok(())
+    }
+}
+
+/// Debug assertion that the number of bytes written by an encoder matches
+/// its reported `len()`, mismatches indicate a bug in the encoder
+#[inline]
+fn debug_assert_len<E: EncodeStr + ?Sized>(e: &E, written: usize) {
+    debug_assert_eq!(written, e.len(),
+        "EncodeStr::write() for {} wrote {} bytes but len() reported {}",
+        core::any::type_name::<E>(), written, e.len());
+}
+
+/// Write an [EncodeStr] value checking the bytes written match `len()` in
+/// debug builds, for use by the write macros
+#[doc(hidden)]
+#[inline]
+pub fn write_checked<E: EncodeStr + ?Sized>(e: &E, buff: &mut [u8]) -> Result<usize, Error> {
+    let n = e.write(buff)?;
+    debug_assert_len(e, n);
+    Ok(n)
+}
+
+/// Find the largest character boundary in the encoded `buff` at or below `n`
+fn floor_char_boundary(buff: &[u8], n: usize) -> usize {
+    let mut n = n.min(buff.len());
+    while n > 0 && n < buff.len() && (buff[n] & 0xc0) == 0x80 {
+        n -= 1;
+    }
+    n
+}
+
+/// Truncating write via [EncodeStr::write_chunks], copying chunks until `buff`
+/// is full, for encoders able to stream values longer than any scratch buffer
+fn write_chunks_truncated<E: EncodeStr>(e: &E, buff: &mut [u8]) -> Result<usize, Error> {
+    let mut scratch = [0u8; TRUNCATE_SCRATCH_LEN];
+    let (mut n, mut done) = (0, false);
+
+    e.write_chunks(&mut scratch, |chunk| {
+        if done {
+            return Ok(())
+        }
+
+        let c = floor_char_boundary(chunk.as_bytes(), buff.len() - n);
+        buff[n..][..c].copy_from_slice(&chunk.as_bytes()[..c]);
+        n += c;
+        done = c < chunk.len();
+
+        Ok(())
+    })?;
+
+    Ok(n)
+}
+
+/// Write one part of a composite value with [WriteMode::Truncate] at offset `n`,
+/// returning whether the part was written in full (and later parts may follow)
+fn write_part_truncated<E: EncodeStr + ?Sized>(e: &E, buff: &mut [u8], n: &mut usize) -> Result<bool, Error> {
+    let m = e.write_with(&mut buff[*n..], WriteMode::Truncate)?;
+    *n += m;
+    Ok(m == e.len())
 }
 
 /// Blanket impl for references implementing [EncodeStr]
@@ -54,9 +275,105 @@ impl <T: EncodeStr> EncodeStr for &T {
     fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
         <T as EncodeStr>::write(self, buff)
     }
+
+    fn validate(&self) -> Result<(), Error> {
+        <T as EncodeStr>::validate(self)
+    }
+
+    unsafe fn write_unchecked(&self, buff: &mut [u8]) -> usize {
+        // SAFETY: forwarded from the caller
+        unsafe { <T as EncodeStr>::write_unchecked(self, buff) }
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        <T as EncodeStr>::write_with(self, buff, mode)
+    }
+
+    fn write_chunks(&self, scratch: &mut [u8], f: impl FnMut(&str) -> Result<(), Error>) -> Result<(), Error> {
+        <T as EncodeStr>::write_chunks(self, scratch, f)
+    }
+}
+
+/// Blanket [EncodeStr] implementation for mutable references to [EncodeStr] types
+impl <T: EncodeStr> EncodeStr for &mut T {
+    fn len(&self) -> usize {
+        <T as EncodeStr>::len(self)
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        <T as EncodeStr>::write(self, buff)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        <T as EncodeStr>::validate(self)
+    }
+
+    unsafe fn write_unchecked(&self, buff: &mut [u8]) -> usize {
+        // SAFETY: forwarded from the caller
+        unsafe { <T as EncodeStr>::write_unchecked(self, buff) }
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        <T as EncodeStr>::write_with(self, buff, mode)
+    }
+
+    fn write_chunks(&self, scratch: &mut [u8], f: impl FnMut(&str) -> Result<(), Error>) -> Result<(), Error> {
+        <T as EncodeStr>::write_chunks(self, scratch, f)
+    }
+}
+
+/// [EncodeStr] implementation for trait object references, allowing
+/// heterogeneous encoders to be stored and iterated (`&[&dyn EncodeStr]`)
+impl EncodeStr for &dyn EncodeStr {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        (**self).write(buff)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        (**self).validate()
+    }
+
+    unsafe fn write_unchecked(&self, buff: &mut [u8]) -> usize {
+        // SAFETY: forwarded from the caller
+        unsafe { (**self).write_unchecked(buff) }
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        (**self).write_with(buff, mode)
+    }
+}
+
+/// Write a list of heterogeneous [EncodeStr] trait objects in order,
+/// returning the number of bytes written on success
+/// 
+/// ```
+/// # use emstr::{EncodeStr, helpers::Hex};
+/// let items: &[&dyn EncodeStr] = &[&"id=", &12u8, &Hex(&[0xab])];
+/// 
+/// let mut buff = [0u8; 16];
+/// let n = emstr::write_all(items, &mut buff).unwrap();
+/// 
+/// assert_eq!(&buff[..n], b"id=12ab");
+/// ```
+pub fn write_all(items: &[&dyn EncodeStr], buff: &mut [u8]) -> Result<usize, Error> {
+    let mut n = 0;
+
+    for i in items {
+        n += i.write(&mut buff[n..])?;
+    }
+
+    Ok(n)
 }
 
 /// Helper macro for joining [EncodeStr] types, returning the number of bytes written on success
+/// 
+/// Arguments are expanded in sequence (without recursion) so there is no
+/// practical limit on the number of arguments. Arguments not implementing
+/// [EncodeStr] are reported by type (`` `X` does not implement `EncodeStr` ``).
 #[macro_export]
 macro_rules! write {
     ($b:expr, $($t:expr),+) => {
@@ -64,7 +381,7 @@ macro_rules! write {
             let mut n = 0;
         
             $(
-                n += EncodeStr::write(& $t, &mut buff[n..])?;
+                n += $crate::write_checked(& $t, &mut buff[n..])?;
             )*
 
             Ok(n)
@@ -72,19 +389,234 @@ macro_rules! write {
     }
 }
 
-/// Helper macro for joining [EncodeStr] types, returning a string slice on success
+/// Helper macro for computing the combined encoded length of [EncodeStr] types
+#[macro_export]
+macro_rules! len {
+    ($($t:expr),+) => {
+        0 $(+ $crate::EncodeStr::len(& $t))+
+    }
+}
+
+/// Helper macro for joining [EncodeStr] types, checking the combined length
+/// prior to writing so the buffer is either fully written or untouched.
+/// 
+/// Returns the number of bytes written on success, or [Error::BufferLength]
+/// where the buffer is shorter than the required length (see [len!]).
+#[macro_export]
+macro_rules! try_write {
+    ($b:expr, $($t:expr),+) => {
+        |buff: &mut [u8]| -> Result<usize, $crate::Error>{
+            // Evaluate each argument once so the checked and written values match
+            let items: &[&dyn $crate::EncodeStr] = &[$(& $t),+];
+
+            if buff.len() < items.iter().map(|i| i.len()).sum::<usize>() {
+                return Err($crate::Error::BufferLength);
+            }
+
+            let mut n = 0;
+            for i in items {
+                n += $crate::write_checked(*i, &mut buff[n..])?;
+            }
+
+            Ok(n)
+        }(&mut $b)
+    }
+}
+
+/// Helper macro for joining [EncodeStr] types with a separator, returning
+/// the number of bytes written on success
+/// 
+/// Arguments with a zero `len()` (such as empty strings) are skipped along
+/// with their separators, so absent fields do not produce doubled separators.
+/// 
+/// ```
+/// let mut buff = [0u8; 16];
+/// let n = emstr::join!(buff, ",", "a", "", 12u8, "c").unwrap();
+/// 
+/// assert_eq!(&buff[..n], b"a,12,c");
+/// ```
+#[macro_export]
+macro_rules! join {
+    ($b:expr, $sep:expr, $($t:expr),+) => {
+        |buff: &mut [u8]| -> Result<usize, $crate::Error>{
+            let sep = & $sep;
+            let mut n = 0;
+
+            $(
+                // Evaluate each argument once for both the skip check and write
+                let v = & $t;
+                if $crate::EncodeStr::len(v) > 0 {
+                    // Only non-empty arguments are written, so any prior output needs a separator
+                    if n > 0 {
+                        n += $crate::EncodeStr::write(sep, &mut buff[n..])?;
+                    }
+
+                    n += $crate::write_checked(v, &mut buff[n..])?;
+                }
+            )*
+
+            Ok(n)
+        }(&mut $b)
+    }
+}
+
+/// Helper macro for appending [EncodeStr] types after existing content,
+/// writing from the provided offset and returning the new total length on success
+/// 
+/// ```
+/// let mut buff = [0u8; 16];
+/// let n = emstr::write!(buff, "hdr").unwrap();
+/// let n = emstr::append!(buff, n, '-', "body").unwrap();
+/// 
+/// assert_eq!(&buff[..n], b"hdr-body");
+/// ```
+/// 
+/// Returns [Error::BufferLength] where the offset exceeds the buffer length.
+#[macro_export]
+macro_rules! append {
+    ($b:expr, $start:expr, $($t:expr),+) => {
+        |buff: &mut [u8], start: usize| -> Result<usize, $crate::Error>{
+            if start > buff.len() {
+                return Err($crate::Error::BufferLength);
+            }
+
+            Ok(start + $crate::write!(buff[start..], $($t),+)?)
+        }(&mut $b, $start)
+    }
+}
+
+/// Helper macro for joining [EncodeStr] types on a best-effort basis, skipping
+/// arguments that fail to encode (or do not fit in the remaining buffer)
+/// and continuing with the next.
+/// 
+/// Returns the number of bytes written and whether any arguments were dropped.
+/// 
+/// ```
+/// let mut buff = [0u8; 8];
+/// let (n, dropped) = emstr::write_best_effort!(buff, "a=", "too long for buffer", 12u8);
+/// 
+/// assert_eq!(&buff[..n], b"a=12");
+/// assert!(dropped);
+/// ```
+#[macro_export]
+macro_rules! write_best_effort {
+    ($b:expr, $($t:expr),+) => {
+        |buff: &mut [u8]| -> (usize, bool) {
+            let mut n = 0;
+            let mut dropped = false;
+
+            $(
+                let v = & $t;
+                match $crate::EncodeStr::len(v) <= buff.len() - n {
+                    true => match $crate::write_checked(v, &mut buff[n..]) {
+                        Ok(w) => n += w,
+                        Err(_) => dropped = true,
+                    },
+                    false => dropped = true,
+                }
+            )*
+
+            (n, dropped)
+        }(&mut $b)
+    }
+}
+
+/// Helper macro for joining [EncodeStr] types, returning a string slice over
+/// the written region on success or [Error::InvalidUtf8] if the output is not valid UTF-8
+/// 
+/// ```
+/// # use emstr::write_str;
+/// let mut buff = [0u8; 32];
+/// let s = write_str!(buff, "count: ", 12u8, '/').unwrap();
+/// 
+/// assert_eq!(s, "count: 12/");
+/// ```
 #[macro_export]
 macro_rules! write_str {
     ($b:expr, $($t:expr),+) => {
-        $crate::write!($b, $($t),+).map(|n| {
-            core::str::from_utf8(& $b[..n]).unwrap()
-        })
+        match $crate::write!($b, $($t),+) {
+            Ok(n) => core::str::from_utf8(& $b[..n]).map_err(|_| $crate::Error::InvalidUtf8),
+            Err(e) => Err(e),
+        }
     }
 }
 
+/// Helper macro for implementing [EncodeStr] for enums (or other types)
+/// by mapping each pattern to a `&'static str` name
+/// 
+/// ```
+/// # use emstr::EncodeStr;
+/// enum Mode {
+///     Idle,
+///     Running(u8),
+/// }
+/// 
+/// emstr::impl_str_enum!(Mode,
+///     Mode::Idle => "idle",
+///     Mode::Running(_) => "running",
+/// );
+/// 
+/// let mut buff = [0u8; 16];
+/// assert_eq!(Mode::Running(1).write_str(&mut buff), Ok("running"));
+/// ```
+#[macro_export]
+macro_rules! impl_str_enum {
+    ($t:ty, $($p:pat => $s:expr),+ $(,)?) => {
+        impl $crate::EncodeStr for $t {
+            fn len(&self) -> usize {
+                let s: &str = match self {
+                    $($p => $s,)+
+                };
+                s.len()
+            }
+
+            fn write(&self, buff: &mut [u8]) -> Result<usize, $crate::Error> {
+                let s: &str = match self {
+                    $($p => $s,)+
+                };
+                $crate::EncodeStr::write(&s, buff)
+            }
+        }
+    };
+}
+
+/// Helper macro for implementing [EncodeStr] for field-less enums using the
+/// variant names, along with [AsDiscriminant](helpers::AsDiscriminant) for
+/// encoding numeric values via [Discriminant](helpers::Discriminant)
+/// 
+/// ```
+/// # use emstr::{EncodeStr, helpers::Discriminant};
+/// enum Mode {
+///     Idle,
+///     Running,
+/// }
+/// 
+/// emstr::impl_enum_str!(Mode { Idle, Running });
+/// 
+/// let mut buff = [0u8; 16];
+/// assert_eq!(Mode::Running.write_str(&mut buff), Ok("Running"));
+/// assert_eq!(Discriminant(Mode::Running).write_str(&mut buff), Ok("1"));
+/// ```
+/// 
+/// See [impl_str_enum!] for custom names or enums with fields.
+#[macro_export]
+macro_rules! impl_enum_str {
+    ($t:ident { $($v:ident),+ $(,)? }) => {
+        $crate::impl_str_enum!($t, $($t::$v => stringify!($v)),+);
+
+        impl $crate::helpers::AsDiscriminant for $t {
+            fn discriminant(&self) -> u32 {
+                match self {
+                    $($t::$v => $t::$v as u32,)+
+                }
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{EncodeStr};
+    use crate::Error;
 
     #[test]
     fn join_str() {
@@ -105,4 +637,429 @@ mod test {
         assert_eq!(n, 6);
         assert_eq!(&buff[..n], b"12/100");
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn encode_to_string() {
+        use crate::{EncodeStr, helpers::Fractional};
+
+        let f = Fractional::new(-1205i32, 100);
+        assert_eq!(f.encode_to_string().unwrap(), "-12.05");
+
+        let mut s = alloc::string::String::from("progress: ");
+        ("abc", ' ', 12u8, '/', 100u8).append_to(&mut s).unwrap();
+        f.append_to(&mut s).unwrap();
+
+        assert_eq!(s, "progress: abc 12/100-12.05");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn join_owned_and_mut() {
+        let mut buff = [0u8; 32];
+
+        let s = alloc::string::String::from("count: ");
+        let mut v = 42u32;
+        let r = &mut v;
+
+        let n = write!(buff, s, r).unwrap();
+        assert_eq!(&buff[..n], b"count: 42");
+    }
+
+    #[test]
+    fn join_write_str() {
+        let mut buff = [0u8; 32];
+
+        let s = write_str!(buff, "abc", '-', 1234u16).unwrap();
+        assert_eq!(s, "abc-1234");
+
+        let s = write_str!(&mut buff[4..], "x", 1u8, "z").unwrap();
+        assert_eq!(s, "x1z");
+
+        let s = write_str!(buff, crate::helpers::Bytes(&[0xff]), 'a');
+        assert_eq!(s, Err(Error::InvalidUtf8));
+
+        let mut buff = [0u8; 4];
+        assert_eq!(write_str!(buff, "abc", '-', 1234u16), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn join_best_effort() {
+        let mut buff = [0u8; 10];
+
+        let (n, dropped) = write_best_effort!(buff, "abc", "0123456789", 42u8, '!');
+        assert_eq!(&buff[..n], b"abc42!");
+        assert!(dropped);
+
+        let (n, dropped) = write_best_effort!(buff, "abc", 42u8);
+        assert_eq!(&buff[..n], b"abc42");
+        assert!(!dropped);
+    }
+
+    #[test]
+    fn join_len() {
+        assert_eq!(len!("abc", ' ', 12345u32), 9);
+    }
+
+    #[test]
+    fn join_separated() {
+        let mut buff = [0u8; 32];
+
+        let n = join!(buff, ',', "a", "b", "c").unwrap();
+        assert_eq!(&buff[..n], b"a,b,c");
+
+        let n = join!(buff, ", ", 1u8, -2i32, '3').unwrap();
+        assert_eq!(&buff[..n], b"1, -2, 3");
+    }
+
+    #[test]
+    fn join_skip_empty() {
+        let mut buff = [0u8; 32];
+
+        // Empty middle argument
+        let n = join!(buff, ',', "a", "", "c").unwrap();
+        assert_eq!(&buff[..n], b"a,c");
+
+        // Empty first argument
+        let n = join!(buff, ',', "", "b", "c").unwrap();
+        assert_eq!(&buff[..n], b"b,c");
+
+        // Empty last and zero length encoders
+        let n = join!(buff, ',', "a", (), "b", "").unwrap();
+        assert_eq!(&buff[..n], b"a,b");
+
+        let n = join!(buff, ',', "", "").unwrap();
+        assert_eq!(n, 0);
+
+        let mut buff = [0u8; 3];
+        assert_eq!(join!(buff, ',', "a", "bc"), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn join_evaluates_once() {
+        let mut buff = [0u8; 16];
+        let mut it = ["a", "", "b", "c"].into_iter();
+
+        // Skip decisions and writes use the same evaluated value
+        let n = join!(buff, ',', it.next().unwrap(), it.next().unwrap(), it.next().unwrap()).unwrap();
+        assert_eq!(&buff[..n], b"a,b");
+        assert_eq!(it.next(), Some("c"));
+    }
+
+    #[test]
+    fn append_offset() {
+        let mut buff = [0u8; 16];
+
+        let n = write!(buff, "hdr").unwrap();
+        let n = append!(buff, n, "-body").unwrap();
+        assert_eq!(n, 8);
+        assert_eq!(&buff[..n], b"hdr-body");
+
+        let n = append!(buff, n, ':', 12u8).unwrap();
+        assert_eq!(&buff[..n], b"hdr-body:12");
+
+        assert_eq!(append!(buff, 14, "abc"), Err(Error::BufferLength));
+        assert_eq!(append!(buff, 17, ""), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn try_write_overflow() {
+        let mut buff = [b'x'; 6];
+
+        let r = try_write!(buff, "abc", 12345u32);
+
+        assert_eq!(r, Err(Error::BufferLength));
+        assert_eq!(len!("abc", 12345u32), 8);
+        assert_eq!(&buff, b"xxxxxx");
+
+        let n = try_write!(buff, "abc", 123u32).unwrap();
+        assert_eq!(&buff[..n], b"abc123");
+    }
+
+    #[test]
+    fn try_write_evaluates_once() {
+        let mut buff = [0u8; 16];
+        let mut it = [1u8, 22, 3, 44].into_iter();
+
+        let n = try_write!(buff, it.next().unwrap(), '/', it.next().unwrap()).unwrap();
+        assert_eq!(&buff[..n], b"1/22");
+        assert_eq!(it.next(), Some(3));
+
+        // Failed length checks consume each argument once
+        let r = try_write!(buff[..3], it.next().unwrap(), "abc");
+        assert_eq!(r, Err(Error::BufferLength));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn impl_str_enum() {
+        use crate::EncodeStr;
+
+        #[allow(dead_code)]
+        enum Example {
+            First,
+            SecondVariant,
+        }
+
+        impl_str_enum!(Example,
+            Example::First => "First",
+            Example::SecondVariant => "SecondVariant",
+        );
+
+        let mut buff = [0u8; 32];
+
+        assert_eq!(Example::First.len(), 5);
+        assert_eq!(Example::SecondVariant.len(), 13);
+        assert_eq!(Example::SecondVariant.write_str(&mut buff), Ok("SecondVariant"));
+    }
+
+    #[test]
+    fn write_uninit() {
+        use core::mem::MaybeUninit;
+        use crate::EncodeStr;
+
+        let v = ("abc", ' ', -1234i32, '/', 100u8);
+
+        let mut a = [MaybeUninit::<u8>::uninit(); 32];
+        let n = v.write_uninit(&mut a).unwrap();
+
+        let mut b = [0u8; 32];
+        let m = v.write(&mut b).unwrap();
+
+        assert_eq!(n, m);
+        for i in 0..n {
+            assert_eq!(unsafe { a[i].assume_init() }, b[i]);
+        }
+
+        let mut a = [MaybeUninit::<u8>::uninit(); 4];
+        assert_eq!(v.write_uninit(&mut a), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn write_unchecked() {
+        use crate::{EncodeStr, helpers::{Hex, Fractional}};
+
+        let items: &[&dyn EncodeStr] = &[
+            &"abc", &"", &0u8, &255u8, &u64::MAX, &-1234i32, &(i64::MIN + 1), &u128::MAX,
+            &Hex([0x01, 0xab, 0xff]), &Fractional::new(-1205i32, 100), &'µ', &("k=", 12u16),
+        ];
+
+        for v in items {
+            let mut a = [0xffu8; 64];
+            let mut b = [0xffu8; 64];
+
+            let m = v.write(&mut b).unwrap();
+
+            // Exactly sized buffer
+            let n = unsafe { v.write_unchecked(&mut a[..v.len()]) };
+
+            assert_eq!(n, m);
+            assert_eq!(a, b);
+        }
+
+        // Sized via len!
+        let mut buff = [0u8; 32];
+        let l = len!("id=", 1234u32, Hex([0xab]));
+        let mut n = unsafe { "id=".write_unchecked(&mut buff[..l]) };
+        n += unsafe { 1234u32.write_unchecked(&mut buff[n..l]) };
+        n += unsafe { Hex([0xab]).write_unchecked(&mut buff[n..l]) };
+        assert_eq!(&buff[..n], b"id=1234ab");
+    }
+
+    #[test]
+    fn write_with_mode() {
+        use crate::{EncodeStr, WriteMode};
+
+        let mut buff = [b'x'; 4];
+
+        // Strings
+        assert_eq!("abcdef".write_with(&mut buff, WriteMode::Strict), Err(Error::BufferLength));
+        assert_eq!("abcdef".write_with(&mut buff, WriteMode::Truncate), Ok(4));
+        assert_eq!(&buff, b"abcd");
+
+        assert_eq!("ab".write_with(&mut buff, WriteMode::Truncate), Ok(2));
+        assert_eq!(&buff[..2], b"ab");
+
+        // Truncation backs off to a character boundary
+        assert_eq!("abcµ".write_with(&mut buff, WriteMode::Truncate), Ok(3));
+        assert_eq!(&buff[..3], b"abc");
+
+        // Integers
+        assert_eq!(123456u32.write_with(&mut buff, WriteMode::Strict), Err(Error::BufferLength));
+        assert_eq!(123456u32.write_with(&mut buff, WriteMode::Truncate), Ok(4));
+        assert_eq!(&buff, b"1234");
+
+        assert_eq!((-12i8).write_with(&mut buff, WriteMode::Strict), Ok(3));
+        assert_eq!(&buff[..3], b"-12");
+
+        // Forwarded through references and trait objects
+        let v: &dyn EncodeStr = &"abcdef";
+        assert_eq!(v.write_with(&mut buff[..2], WriteMode::Truncate), Ok(2));
+        assert_eq!(&buff[..2], b"ab");
+    }
+
+    #[test]
+    fn write_with_long_values() {
+        use crate::{EncodeStr, WriteMode, helpers::{Hex, Join, PadLeft, PadRight, Wrap}};
+
+        let mut buff = [0u8; 10];
+        let data = [0xab; 100];
+
+        // Chunked hex encoding
+        assert_eq!(Hex(&data).write_with(&mut buff, WriteMode::Truncate), Ok(10));
+        assert_eq!(&buff, b"ababababab");
+
+        let mut buff = [0u8; 12];
+        let long = "x".repeat(200);
+
+        // Tuples and joins truncate members in order
+        let v = ("ab", long.as_str(), "cd");
+        assert_eq!(v.write_with(&mut buff, WriteMode::Truncate), Ok(12));
+        assert_eq!(&buff, b"abxxxxxxxxxx");
+
+        let items = [long.as_str(), long.as_str()];
+        let v = Join::new(&items, ", ");
+        assert_eq!(v.write_with(&mut buff, WriteMode::Truncate), Ok(12));
+        assert_eq!(&buff, b"xxxxxxxxxxxx");
+
+        let items = ["abcd", "efgh", long.as_str()];
+        let v = Join::new(&items, ", ");
+        assert_eq!(v.write_with(&mut buff, WriteMode::Truncate), Ok(12));
+        assert_eq!(&buff, b"abcd, efgh, ");
+
+        // Wrappers and padding
+        let v = Wrap::new("[", Hex(&data), "]");
+        assert_eq!(v.write_with(&mut buff, WriteMode::Truncate), Ok(12));
+        assert_eq!(&buff, b"[abababababa");
+
+        let v = PadRight::new("abc", 200, '.');
+        assert_eq!(v.write_with(&mut buff, WriteMode::Truncate), Ok(12));
+        assert_eq!(&buff, b"abc.........");
+
+        let v = PadLeft::new("abc", 200, '.');
+        assert_eq!(v.write_with(&mut buff, WriteMode::Truncate), Ok(12));
+        assert_eq!(&buff, b"............");
+
+        let v = PadLeft::new(long.as_str(), 205, '.');
+        assert_eq!(v.write_with(&mut buff, WriteMode::Truncate), Ok(12));
+        assert_eq!(&buff, b".....xxxxxxx");
+
+        // Strict mode still rejects over-length values
+        assert_eq!(v.write_with(&mut buff, WriteMode::Strict), Err(Error::BufferLength));
+    }
+
+    #[test]
+    #[should_panic(expected = "write_unchecked failed: TooWide")]
+    fn write_unchecked_fallible() {
+        use crate::{EncodeStr, helpers::Bounded};
+
+        let mut buff = [0u8; 32];
+        let v = Bounded::new(12345u32, 4);
+
+        unsafe { v.write_unchecked(&mut buff) };
+    }
+
+    #[test]
+    fn to_array() {
+        use crate::{EncodeStr, helpers::Fractional};
+
+        let (a, n) = 12345u32.to_array::<16>().unwrap();
+        assert_eq!(&a[..n], b"12345");
+
+        let (a, n) = Fractional::new(-1205i32, 100).to_array::<8>().unwrap();
+        assert_eq!(&a[..n], b"-12.05");
+
+        assert_eq!(12345u32.to_array::<4>(), Err(Error::BufferLength));
+    }
+
+    /// Encoder with a `len()` that disagrees with `write()`
+    struct Mismatched;
+
+    impl crate::EncodeStr for Mismatched {
+        fn len(&self) -> usize {
+            2
+        }
+
+        fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+            "abc".write(buff)
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "wrote 3 bytes but len() reported 2")]
+    fn write_len_mismatch() {
+        use crate::EncodeStr;
+
+        let mut buff = [0u8; 8];
+        let _ = Mismatched.write_str(&mut buff);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "wrote 3 bytes but len() reported 2")]
+    fn write_macro_len_mismatch() {
+        let mut buff = [0u8; 8];
+        let _ = write!(buff, "a", Mismatched);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "wrote 3 bytes but len() reported 2")]
+    fn buf_writer_len_mismatch() {
+        let mut buff = [0u8; 8];
+        let _ = crate::BufWriter::new(&mut buff).write(&Mismatched);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "wrote 3 bytes but len() reported 2")]
+    fn ring_writer_len_mismatch() {
+        let mut buff = [0u8; 8];
+        let _ = crate::RingWriter::new(&mut buff).write(&Mismatched);
+    }
+
+    #[test]
+    fn write_len_matches() {
+        use crate::{EncodeStr, helpers::*};
+
+        let mut buff = [0u8; 64];
+
+        // Spot check helpers, each write_str call asserts len() in debug builds
+        Fractional::new(-5i32, 100).write_str(&mut buff).unwrap();
+        Fractional::fixed(1200i32, 1000).write_str(&mut buff).unwrap();
+        Fractional::new(1i32, 3).write_str(&mut buff).unwrap();
+        Grouped::new(-1234567i64).write_str(&mut buff).unwrap();
+        Money::new(-500i32, "$").write_str(&mut buff).unwrap();
+        Hex0x::new(&[0xab, 0xcd]).write_str(&mut buff).unwrap();
+        PadLeft::new(12u8, 6, ' ').write_str(&mut buff).unwrap();
+        JsonStr::quoted("a\"b\n").write_str(&mut buff).unwrap();
+        Chunked::new("ABCDEFGH", 3, '-').write_str(&mut buff).unwrap();
+        DataSize::new(1_500_000).write_str(&mut buff).unwrap();
+        ("abc", ' ', -12i8).write_str(&mut buff).unwrap();
+    }
+
+    #[test]
+    fn write_dyn() {
+        use crate::{EncodeStr, helpers::{Hex, Fractional}};
+
+        let f = Fractional::new(-125i32, 100);
+        let items: &[&dyn EncodeStr] = &[&"abc", &' ', &1234u32, &Hex(&[0xde, 0xad]), &f];
+
+        let mut buff = [0u8; 32];
+        let n = crate::write_all(items, &mut buff).unwrap();
+        assert_eq!(&buff[..n], b"abc 1234dead-1.25");
+
+        // Slices of trait objects are themselves encodable
+        assert_eq!(EncodeStr::len(&items), n);
+        assert_eq!(items.write_str(&mut buff), Ok("abc 1234dead-1.25"));
+
+        // Trait objects compose with the write! macro
+        let d: &dyn EncodeStr = &42u8;
+        let n = write!(buff, "v=", d).unwrap();
+        assert_eq!(&buff[..n], b"v=42");
+
+        let mut buff = [0u8; 8];
+        assert_eq!(crate::write_all(items, &mut buff), Err(Error::BufferLength));
+    }
 }
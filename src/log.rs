@@ -0,0 +1,54 @@
+//! [defmt] integration for logging [EncodeStr] values
+//! 
+//! ```
+//! # use emstr::{DefmtStr, helpers::Fractional};
+//! let v = Fractional::new(-1205i32, 100);
+//! 
+//! // Encodes to a 16 byte stack buffer when formatted
+//! let l = DefmtStr::<_, 16>::new(v);
+//! # let _ = l;
+//! // defmt::info!("value: {}", l);
+//! ```
+
+use crate::EncodeStr;
+
+/// Helper for logging [EncodeStr] values via [defmt], encoding to an `N` byte
+/// stack buffer and emitting the resulting `&str` when formatted.
+/// 
+/// `N` trades stack usage against the longest value that can be logged,
+/// values exceeding the buffer are logged as the encoding [Error](crate::Error).
+pub struct DefmtStr<E: EncodeStr, const N: usize>(E);
+
+impl <E: EncodeStr, const N: usize> DefmtStr<E, N> {
+    /// Create a new defmt logging wrapper for the provided value
+    pub const fn new(inner: E) -> Self {
+        Self(inner)
+    }
+}
+
+/// [defmt::Format] implementation for [DefmtStr]
+impl <E: EncodeStr, const N: usize> defmt::Format for DefmtStr<E, N> {
+    fn format(&self, f: defmt::Formatter) {
+        let mut buff = [0u8; N];
+
+        match self.0.write_str(&mut buff) {
+            Ok(s) => defmt::write!(f, "{=str}", s),
+            Err(e) => defmt::write!(f, "<{}>", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Error;
+    use super::DefmtStr;
+
+    fn assert_format<T: defmt::Format>() {}
+
+    #[test]
+    fn defmt_impls() {
+        assert_format::<Error>();
+        assert_format::<DefmtStr<u32, 16>>();
+        assert_format::<DefmtStr<&str, 8>>();
+    }
+}
@@ -0,0 +1,88 @@
+//! [EncodeStr] implementations for slices of encodable types
+//! 
+//! Arrays are encoded via slices (`&arr[..]`), as implementing [EncodeStr]
+//! for `[T; N]` would shadow the inherent `len()` method on arrays
+//! (and byte buffers) wherever [EncodeStr] is in scope.
+
+use crate::{EncodeStr, Error};
+
+/// Sum the encoded lengths of a slice of items
+fn slice_len<T: EncodeStr>(items: &[T]) -> usize {
+    items.iter().map(|i| i.len()).sum()
+}
+
+/// Write a slice of items in order
+fn slice_write<T: EncodeStr>(items: &[T], buff: &mut [u8]) -> Result<usize, Error> {
+    let mut n = 0;
+
+    for i in items {
+        n += i.write(&mut buff[n..])?;
+    }
+
+    Ok(n)
+}
+
+/// Validate a slice of items
+fn slice_validate<T: EncodeStr>(items: &[T]) -> Result<(), Error> {
+    items.iter().try_for_each(|i| i.validate())
+}
+
+/// [EncodeStr] implementation for slices, writing members in order
+impl <T: EncodeStr> EncodeStr for &[T] {
+    fn len(&self) -> usize {
+        slice_len(self)
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        slice_write(self, buff)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        slice_validate(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error};
+
+    #[test]
+    fn encode_array() {
+        let a = ["a", "b", "c"];
+        let v = &a[..];
+
+        let mut buff = [0u8; 32];
+        let s = v.write_str(&mut buff).unwrap();
+
+        assert_eq!(s, "abc");
+        assert_eq!(EncodeStr::len(&v), s.len());
+    }
+
+    #[test]
+    fn encode_array_empty() {
+        let v: &[&str] = &[];
+
+        let mut buff = [0u8; 32];
+        assert_eq!(EncodeStr::len(&v), 0);
+        assert_eq!(v.write_str(&mut buff), Ok(""));
+    }
+
+    #[test]
+    fn encode_slice() {
+        let v: &[u16] = &[1, 22, 333];
+
+        let mut buff = [0u8; 32];
+        let s = v.write_str(&mut buff).unwrap();
+
+        assert_eq!(s, "122333");
+        assert_eq!(EncodeStr::len(&v), s.len());
+    }
+
+    #[test]
+    fn encode_array_buffer_length() {
+        let v: &[&str] = &["abc", "def", "ghi"];
+
+        let mut buff = [0u8; 7];
+        assert_eq!(v.write(&mut buff), Err(Error::BufferLength));
+    }
+}
@@ -0,0 +1,65 @@
+//! [EncodeStr] implementations for floating point primitives, see [Float]
+
+use crate::{EncodeStr, Error, helpers::Float};
+
+/// Default number of decimal places for float encoding
+const DECIMALS: u32 = 3;
+
+/// [EncodeStr] implementation for [f32], using [DECIMALS] decimal places
+impl EncodeStr for f32 {
+    fn len(&self) -> usize {
+        Float::new(*self, DECIMALS).len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        Float::new(*self, DECIMALS).write(buff)
+    }
+}
+
+/// [EncodeStr] implementation for [f64], using [DECIMALS] decimal places
+impl EncodeStr for f64 {
+    fn len(&self) -> usize {
+        Float::new(*self, DECIMALS).len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        Float::new(*self, DECIMALS).write(buff)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use crate::EncodeStr;
+
+    #[test]
+    fn encode_f32() {
+        let tests: &[f32] = &[0.0, 1.0, 1.5, -2.25, 0.1, 123.456, -0.02, 65535.0];
+
+        for v in tests {
+            let mut buff = [0u8; 32];
+
+            let s = alloc::format!("{:.3}", v);
+            let e = v.write_str(&mut buff).unwrap();
+
+            assert_eq!(v.len(), s.len(), "length mismatch for value: {}", v);
+            assert_eq!(e, &s);
+        }
+    }
+
+    #[test]
+    fn encode_f64() {
+        let tests: &[f64] = &[0.0, 1.0, 1.5, -2.25, 0.1, 123.456, -0.02, 1e12, -0.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+
+        for v in tests {
+            let mut buff = [0u8; 32];
+
+            let s = alloc::format!("{:.3}", v);
+            let e = v.write_str(&mut buff).unwrap();
+
+            assert_eq!(v.len(), s.len(), "length mismatch for value: {}", v);
+            assert_eq!(e, &s);
+        }
+    }
+}
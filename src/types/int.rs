@@ -1,106 +1,152 @@
 
-use crate::Error;
+use core::num::{
+    Wrapping, Saturating,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+};
+
+use num_traits::{PrimInt, Signed};
+
+use crate::{Error, const_len::{uint_len, int_len}};
 use super::EncodeStr;
 
 /// Character map for integer encoding
 const CHAR_MAP: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
 
-/// Helper macro for implementing unsigned integer string encoding
-macro_rules! impl_uint_encode {
-    ($t:ty) => {
+/// Scratch length for integer encoding, sufficient for [i128::MIN]
+const SCRATCH_LEN: usize = 40;
+
+/// Encode the digits of a non-negative integer back-to-front into `scratch`,
+/// returning the index of the first character
+#[inline(always)]
+fn encode_digits<T: PrimInt>(mut v: T, scratch: &mut [u8; SCRATCH_LEN]) -> usize {
+    let ten = T::from(10u8).unwrap();
+    let mut i = SCRATCH_LEN;
+
+    // Write digits back-to-front, counting as we go
+    loop {
+        let r = (v % ten).to_usize().unwrap_or(0);
+        v = v / ten;
+
+        i -= 1;
+        scratch[i] = CHAR_MAP[r] as u8;
+
+        if v.is_zero() {
+            break;
+        }
+    }
+
+    i
+}
+
+/// Encode a signed integer back-to-front into `scratch`, returning the
+/// index of the first character
+#[inline(always)]
+fn encode_signed<T: PrimInt + Signed>(v: T, scratch: &mut [u8; SCRATCH_LEN]) -> usize {
+    if v >= T::zero() {
+        return encode_digits(v, scratch);
+    }
+
+    // Handle negatives without negating the value, as `-T::MIN` overflows
+    let ten = T::from(10u8).unwrap();
+    let mut v = v;
+    let mut i = SCRATCH_LEN;
+
+    loop {
+        let r = (-(v % ten)).to_usize().unwrap_or(0);
+        v = v / ten;
+
+        i -= 1;
+        scratch[i] = CHAR_MAP[r] as u8;
+
+        if v.is_zero() {
+            break;
+        }
+    }
+
+    i -= 1;
+    scratch[i] = b'-';
+
+    i
+}
+
+/// Copy encoded characters from `scratch[i..]` into the output buffer
+/// 
+/// # Safety
+/// `buff` must be at least `SCRATCH_LEN - i` bytes long
+#[inline(always)]
+unsafe fn copy_unchecked(scratch: &[u8; SCRATCH_LEN], i: usize, buff: &mut [u8]) -> usize {
+    let n = SCRATCH_LEN - i;
+    debug_assert!(buff.len() >= n);
+
+    // SAFETY: `i < SCRATCH_LEN` and the caller guarantees `buff` has at least `n` bytes
+    unsafe { core::ptr::copy_nonoverlapping(scratch.as_ptr().add(i), buff.as_mut_ptr(), n) };
+
+    n
+}
+
+/// Helper macro for implementing integer string encoding, using the
+/// provided function to encode into a scratch buffer
+macro_rules! impl_int_encode {
+    ($t:ty, $len:expr, $encode:ident) => {
         impl EncodeStr for $t {
             fn len(&self) -> usize {
-                let mut v = *self;
-                let mut n = 0;
-        
-                // Handle zero
-                if v == 0 {
-                    return 1;
-                }
-
-                while v > 0 {
-                    v /= 10;
-                    n += 1;
-                }
-        
-                n
+                $len(*self)
             }
         
             fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
-                let n = self.len();
-                let mut v = *self;
-        
+                let mut scratch = [0u8; SCRATCH_LEN];
+                let i = $encode(*self, &mut scratch);
+
+                // Check buffer length
+                let n = SCRATCH_LEN - i;
                 if buff.len() < n {
                     return Err(Error::BufferLength);
                 }
-        
-                for i in 0..n {
-                    let r = (v % 10) as usize;
-                    v /= 10;
-        
-                    buff[n - i - 1] = CHAR_MAP[r] as u8;
-                }
+
+                buff[..n].copy_from_slice(&scratch[i..]);
         
                 Ok(n)
             }
+
+            unsafe fn write_unchecked(&self, buff: &mut [u8]) -> usize {
+                let mut scratch = [0u8; SCRATCH_LEN];
+                let i = $encode(*self, &mut scratch);
+
+                // SAFETY: the caller guarantees `buff` is at least `len()` bytes
+                unsafe { copy_unchecked(&scratch, i, buff) }
+            }
         }
     };
 }
 
+/// Helper macro for implementing unsigned integer string encoding
+macro_rules! impl_uint_encode {
+    ($t:ty) => {
+        impl_int_encode!($t, |v| uint_len(v as u128), encode_digits);
+    };
+}
+
 /// Helper macro for implementing signed integer string encoding
 macro_rules! impl_sint_encode {
     ($t:ty) => {
+        impl_int_encode!($t, |v| int_len(v as i128), encode_signed);
+    };
+}
+
+/// Helper macro for implementing string encoding for wrapped integers,
+/// delegating to the inner value
+macro_rules! impl_wrapped_encode {
+    ($t:ty, $v:ident => $e:expr) => {
         impl EncodeStr for $t {
             fn len(&self) -> usize {
-                let mut v = *self;
-                let mut n = 0;
-        
-                // Handle zero
-                if v == 0 {
-                    return 1;
-                }
-
-                // Handle negatives
-                if v < 0 {
-                    n += 1;
-                    v = -v;
-                }
-
-                // Compute required characters
-                while v > 0 {
-                    v /= 10;
-                    n += 1;
-                }
-        
-                n
+                let $v = self;
+                EncodeStr::len(&$e)
             }
-        
+
             fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
-                let n = self.len();
-                let mut v = *self;
-                
-                // Check buffer length
-                if buff.len() < n {
-                    return Err(Error::BufferLength);
-                }
-        
-                // Handle negatives
-                let c = if v < 0 {
-                    buff[0] = '-' as u8;
-                    v = -v;
-                    n - 1
-                } else {
-                    n
-                };
-
-                for i in 0..c {
-                    let r = (v % 10) as usize;
-                    v /= 10;
-        
-                    buff[n - i - 1] = CHAR_MAP[r] as u8;
-                }
-        
-                Ok(n)
+                let $v = self;
+                EncodeStr::write(&$e, buff)
             }
         }
     };
@@ -110,13 +156,59 @@ impl_uint_encode!(u8);
 impl_uint_encode!(u16);
 impl_uint_encode!(u32);
 impl_uint_encode!(u64);
+impl_uint_encode!(u128);
 impl_uint_encode!(usize);
 
 impl_sint_encode!(i8);
 impl_sint_encode!(i16);
 impl_sint_encode!(i32);
 impl_sint_encode!(i64);
-impl_uint_encode!(isize);
+impl_sint_encode!(i128);
+impl_sint_encode!(isize);
+
+impl_wrapped_encode!(NonZeroU8, v => v.get());
+impl_wrapped_encode!(NonZeroU16, v => v.get());
+impl_wrapped_encode!(NonZeroU32, v => v.get());
+impl_wrapped_encode!(NonZeroU64, v => v.get());
+impl_wrapped_encode!(NonZeroU128, v => v.get());
+impl_wrapped_encode!(NonZeroUsize, v => v.get());
+
+impl_wrapped_encode!(NonZeroI8, v => v.get());
+impl_wrapped_encode!(NonZeroI16, v => v.get());
+impl_wrapped_encode!(NonZeroI32, v => v.get());
+impl_wrapped_encode!(NonZeroI64, v => v.get());
+impl_wrapped_encode!(NonZeroI128, v => v.get());
+impl_wrapped_encode!(NonZeroIsize, v => v.get());
+
+/// [EncodeStr] implementation for [Wrapping] integers, delegating to the inner value
+impl <T: EncodeStr> EncodeStr for Wrapping<T> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        self.0.write(buff)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.0.validate()
+    }
+}
+
+/// [EncodeStr] implementation for [Saturating] integers, delegating to the inner value
+impl <T: EncodeStr> EncodeStr for Saturating<T> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        self.0.write(buff)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.0.validate()
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -140,7 +232,7 @@ mod test {
 
     #[test]
     fn encode_i8() {
-        for i in i8::MIN+1..i8::MAX {
+        for i in i8::MIN..=i8::MAX {
             let mut buff = [0u8; 32];
 
             let s = alloc::format!("{}", i);
@@ -168,7 +260,7 @@ mod test {
 
     #[test]
     fn encode_i16() {
-        for i in i16::MIN+1..i16::MAX {
+        for i in i16::MIN..=i16::MAX {
             let mut buff = [0u8; 32];
 
             let s = alloc::format!("{}", i);
@@ -211,7 +303,7 @@ mod test {
             (-1243566, "-1243566"),
             (i64::MAX, "9223372036854775807"),
             (i64::MIN + 1, "-9223372036854775807"),
-            // TODO: handle actual i64::MIN
+            (i64::MIN, "-9223372036854775808"),
         ];
 
         for (v, s) in tests {
@@ -224,4 +316,99 @@ mod test {
             assert_eq!(e, *s, "encode failed for value: {}", v);
         }
     }
+
+    #[test]
+    fn encode_nonzero() {
+        use core::num::{NonZeroU16, NonZeroI32, NonZeroU64};
+
+        let mut buff = [0u8; 32];
+
+        let v = NonZeroU16::new(1234).unwrap();
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.write_str(&mut buff), Ok("1234"));
+
+        let v = NonZeroI32::new(-42).unwrap();
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.write_str(&mut buff), Ok("-42"));
+
+        let v = NonZeroU64::new(u64::MAX).unwrap();
+        assert_eq!(v.write_str(&mut buff), Ok("18446744073709551615"));
+    }
+
+    #[test]
+    fn encode_wrapping() {
+        use core::num::Wrapping;
+
+        let mut buff = [0u8; 32];
+
+        let v = Wrapping(4200u32);
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.write_str(&mut buff), Ok("4200"));
+
+        let v = Wrapping(u8::MAX) + Wrapping(2);
+        assert_eq!(v.write_str(&mut buff), Ok("1"));
+
+        let n = crate::write!(buff, "count=", Wrapping(-12i16)).unwrap();
+        assert_eq!(&buff[..n], b"count=-12");
+    }
+
+    #[test]
+    fn encode_saturating() {
+        use core::num::Saturating;
+
+        let mut buff = [0u8; 32];
+
+        let v = Saturating(255u8);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.write_str(&mut buff), Ok("255"));
+
+        let v = Saturating(250u8) + Saturating(10);
+        let n = crate::write!(buff, "count=", v, '/', 255u8).unwrap();
+        assert_eq!(&buff[..n], b"count=255/255");
+    }
+
+    /// Number of random values checked per integer type
+    const ITERATIONS: usize = 10_000;
+
+    /// Helper macro for checking random and boundary integer values against
+    /// [core::fmt::Display], for both `len()` and `write_str`
+    macro_rules! check_random {
+        ($($t:ty),+) => {
+            $(
+                let mut buff = [0u8; 40];
+
+                let values = [<$t>::MIN, <$t>::MIN + 1, 0, 1, <$t>::MAX - 1, <$t>::MAX].into_iter()
+                    .chain((0..ITERATIONS).map(|_| rand::random::<$t>() >> (rand::random::<u32>() % <$t>::BITS)));
+
+                for v in values {
+                    let s = alloc::format!("{}", v);
+
+                    assert_eq!(v.len(), s.len(), "length mismatch for {} value: {}", stringify!($t), v);
+                    assert_eq!(v.write_str(&mut buff), Ok(s.as_str()), "encode failed for {} value: {}", stringify!($t), v);
+                    assert_eq!(v.write(&mut buff[..s.len() - 1]), Err(crate::Error::BufferLength));
+                }
+            )+
+        };
+    }
+
+    #[test]
+    fn encode_random_unsigned() {
+        check_random!(u8, u16, u32, u64, u128, usize);
+    }
+
+    #[test]
+    fn encode_random_signed() {
+        check_random!(i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn encode_extremes() {
+        let mut buff = [0u8; 40];
+
+        assert_eq!(u128::MAX.write_str(&mut buff), Ok("340282366920938463463374607431768211455"));
+        assert_eq!(i128::MIN.write_str(&mut buff), Ok("-170141183460469231731687303715884105728"));
+        assert_eq!(i128::MAX.write_str(&mut buff), Ok("170141183460469231731687303715884105727"));
+        assert_eq!(isize::MIN.len(), alloc::format!("{}", isize::MIN).len());
+        assert_eq!((-1isize).write_str(&mut buff), Ok("-1"));
+    }
 }
@@ -2,14 +2,29 @@
 
 //! [EncodeStr] implementations for primitive
 
-use crate::{EncodeStr, Error};
+use core::{cmp::Ordering, ffi::CStr, marker::PhantomData};
+
+use crate::{EncodeStr, Error, WriteMode};
 
 mod int;
 
+mod net;
+
+mod tuple;
+
+mod array;
+
+mod result;
+
+mod time;
+
+#[cfg(feature = "float")]
+mod float;
+
 /// [EncodeStr] implementation for [str] references
 impl EncodeStr for &str {
     fn len(&self) -> usize {
-        self.as_bytes().len()
+        str::len(self)
     }
 
     fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
@@ -18,32 +33,123 @@ impl EncodeStr for &str {
             return Err(Error::BufferLength)
         }
 
-        buff[..n].copy_from_slice(&self.as_bytes());
+        buff[..n].copy_from_slice(self.as_bytes());
 
         Ok(n)
     }
+
+    unsafe fn write_unchecked(&self, buff: &mut [u8]) -> usize {
+        let n = str::len(self);
+        debug_assert!(buff.len() >= n);
+
+        // SAFETY: the caller guarantees `buff` is at least `len()` bytes
+        unsafe { core::ptr::copy_nonoverlapping(self.as_ptr(), buff.as_mut_ptr(), n) };
+
+        n
+    }
+
+    fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+        match mode {
+            WriteMode::Strict => self.write(buff),
+            // Truncate in place at a character boundary
+            WriteMode::Truncate => {
+                let mut n = buff.len().min(str::len(self));
+                while !self.is_char_boundary(n) {
+                    n -= 1;
+                }
+
+                buff[..n].copy_from_slice(&self.as_bytes()[..n]);
+
+                Ok(n)
+            },
+        }
+    }
+
+    fn write_chunks(&self, _scratch: &mut [u8], mut f: impl FnMut(&str) -> Result<(), Error>) -> Result<(), Error> {
+        // Already encoded, pass through without copying
+        f(self)
+    }
 }
 
-/// [EncodeStr] implementation for [char]s
+/// [EncodeStr] implementation for [CStr] references, writing the content
+/// up to (and excluding) the NUL terminator
+/// 
+/// C strings may not be valid UTF-8, in which case `write_str` will return
+/// [Error::InvalidUtf8]
+impl EncodeStr for &CStr {
+    fn len(&self) -> usize {
+        self.to_bytes().len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        let b = self.to_bytes();
+
+        // Check buffer length
+        if buff.len() < b.len() {
+            return Err(Error::BufferLength)
+        }
+
+        buff[..b.len()].copy_from_slice(b);
+
+        Ok(b.len())
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.to_str().map(|_| ()).map_err(|_| Error::InvalidUtf8)
+    }
+}
+
+/// [EncodeStr] implementation for owned [String](alloc::string::String)s
+#[cfg(feature = "alloc")]
+impl EncodeStr for alloc::string::String {
+    fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        self.as_str().write(buff)
+    }
+}
+
+// [EncodeStr] implementation for [Ordering] variant names
+crate::impl_str_enum!(Ordering,
+    Ordering::Less => "Less",
+    Ordering::Equal => "Equal",
+    Ordering::Greater => "Greater",
+);
+
+/// [EncodeStr] implementation for [char]s, written as UTF-8
 impl EncodeStr for char {
     fn len(&self) -> usize {
-        1
+        self.len_utf8()
     }
 
     fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
-        if 1 > buff.len() {
+        let n = self.len_utf8();
+        if buff.len() < n {
             return Err(Error::BufferLength)
         }
 
-        buff[0] = *self as u8;
+        self.encode_utf8(buff);
 
-        Ok(1)
+        Ok(n)
+    }
+}
+
+/// [EncodeStr] implementation for [PhantomData] markers, encoding to an empty string
+impl <T: ?Sized> EncodeStr for PhantomData<T> {
+    fn len(&self) -> usize {
+        0
+    }
+
+    fn write(&self, _buff: &mut [u8]) -> Result<usize, Error> {
+        Ok(0)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::EncodeStr;
+    use crate::{EncodeStr, Error};
 
     #[test]
     fn write_str() {
@@ -56,6 +162,24 @@ mod test {
         assert_eq!(&buff[..n], v.as_bytes());
     }
 
+    #[test]
+    fn encode_ordering() {
+        use core::cmp::Ordering;
+
+        let tests = &[
+            (Ordering::Less, "Less"),
+            (Ordering::Equal, "Equal"),
+            (Ordering::Greater, "Greater"),
+        ];
+
+        for (v, s) in tests {
+            let mut buff = [0u8; 32];
+
+            assert_eq!(v.len(), s.len());
+            assert_eq!(v.write_str(&mut buff), Ok(*s));
+        }
+    }
+
     #[test]
     fn encode_char() {
         let v = 'c';
@@ -66,4 +190,89 @@ mod test {
         assert_eq!(n, 1);
         assert_eq!(&buff[..n], "c".as_bytes());
     }
+
+    #[test]
+    fn encode_char_utf8() {
+        let tests = &[
+            ('a', "a"),
+            ('µ', "µ"),
+            ('€', "€"),
+            ('\u{202f}', "\u{202f}"),
+            ('🦀', "🦀"),
+        ];
+
+        for (c, s) in tests {
+            let mut buff = [0u8; 8];
+
+            assert_eq!(c.len(), s.len());
+            assert_eq!(c.write_str(&mut buff), Ok(*s));
+        }
+
+        let mut buff = [0u8; 2];
+        assert_eq!('€'.write(&mut buff), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn encode_char_slice() {
+        let tests: &[(&[char], &str)] = &[
+            (&['a', 'b', 'c'], "abc"),
+            (&['µ', 's', ' ', '✓'], "µs ✓"),
+            (&[], ""),
+        ];
+
+        for (v, s) in tests {
+            let mut buff = [0u8; 32];
+
+            assert_eq!(EncodeStr::len(v), s.len());
+            assert_eq!(v.write_str(&mut buff), Ok(*s));
+        }
+
+        // One byte short of the trailing multi-byte char
+        let v: &[char] = &['a', '€'];
+        let mut buff = [0u8; 3];
+        assert_eq!(v.write(&mut buff), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn encode_phantom_data() {
+        use core::marker::PhantomData;
+
+        let mut buff = [0u8; 32];
+
+        let p = PhantomData::<u32>;
+        assert_eq!(p.len(), 0);
+        assert_eq!(p.write_str(&mut []), Ok(""));
+
+        let n = crate::write!(buff, "id=", p, 12u8, PhantomData::<str>).unwrap();
+        assert_eq!(&buff[..n], b"id=12");
+    }
+
+    #[test]
+    fn encode_cstr() {
+        let mut buff = [0u8; 32];
+
+        let c = c"hello";
+        assert_eq!(c.len(), 5);
+        assert_eq!(c.write_str(&mut buff), Ok("hello"));
+
+        let n = crate::write!(buff, "name=", c, ';').unwrap();
+        assert_eq!(&buff[..n], b"name=hello;");
+
+        let mut buff = [0u8; 4];
+        assert_eq!(c.write(&mut buff), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn encode_cstr_invalid_utf8() {
+        let mut buff = [0u8; 32];
+
+        let c = c"ab\xffcd";
+        assert_eq!(c.len(), 5);
+
+        // Raw bytes are still written
+        assert_eq!(c.write(&mut buff), Ok(5));
+        assert_eq!(&buff[..5], b"ab\xffcd");
+
+        assert_eq!(c.write_str(&mut buff), Err(Error::InvalidUtf8));
+    }
 }
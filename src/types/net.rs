@@ -0,0 +1,296 @@
+//! [EncodeStr] implementations for [core::net] addresses
+
+use core::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::{EncodeStr, Error};
+
+/// Value to character mapping for IPv6 segments
+const HEX_MAP: [u8; 16] = *b"0123456789abcdef";
+
+/// Prefix for IPv4-mapped IPv6 addresses
+const MAPPED_PREFIX: &str = "::ffff:";
+
+/// [EncodeStr] implementation for [Ipv4Addr] as a dotted quad (`192.168.0.1`)
+impl EncodeStr for Ipv4Addr {
+    fn len(&self) -> usize {
+        let o = self.octets();
+        o.iter().map(|v| v.len()).sum::<usize>() + 3
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write octets separated by '.'
+        let mut n = 0;
+        for (i, v) in self.octets().iter().enumerate() {
+            if i > 0 {
+                n += '.'.write(&mut buff[n..])?;
+            }
+            n += v.write(&mut buff[n..])?;
+        }
+
+        Ok(n)
+    }
+}
+
+/// [EncodeStr] implementation for [Ipv6Addr] using the RFC 5952 compressed
+/// form (`2001:db8::1`), with IPv4-mapped addresses as `::ffff:192.168.0.1`
+impl EncodeStr for Ipv6Addr {
+    fn len(&self) -> usize {
+        // Handle IPv4-mapped addresses
+        if let Some(v4) = mapped(self) {
+            return MAPPED_PREFIX.len() + v4.len();
+        }
+
+        let s = self.segments();
+
+        match zero_run(&s) {
+            Some((start, len)) => {
+                let (l, r) = (&s[..start], &s[start + len..]);
+                joined_len(l) + 2 + joined_len(r)
+            },
+            None => joined_len(&s),
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        // Write IPv4-mapped addresses
+        if let Some(v4) = mapped(self) {
+            let n = MAPPED_PREFIX.write(buff)?;
+            return Ok(n + v4.write(&mut buff[n..])?);
+        }
+
+        let s = self.segments();
+
+        // Write segments, compressing the longest zero run to '::'
+        let n = match zero_run(&s) {
+            Some((start, len)) => {
+                let mut n = write_joined(&s[..start], buff)?;
+                n += "::".write(&mut buff[n..])?;
+                n += write_joined(&s[start + len..], &mut buff[n..])?;
+                n
+            },
+            None => write_joined(&s, buff)?,
+        };
+
+        Ok(n)
+    }
+}
+
+/// [EncodeStr] implementation for [SocketAddrV4] as `ip:port` (`192.168.0.1:8080`)
+impl EncodeStr for SocketAddrV4 {
+    fn len(&self) -> usize {
+        self.ip().len() + 1 + self.port().len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = self.ip().write(buff)?;
+        n += ':'.write(&mut buff[n..])?;
+        n += self.port().write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+/// [EncodeStr] implementation for [SocketAddrV6] as `[ip]:port` (`[2001:db8::1]:8080`),
+/// including the scope id where non-zero (`[fe80::1%2]:8080`)
+impl EncodeStr for SocketAddrV6 {
+    fn len(&self) -> usize {
+        let scope = match self.scope_id() {
+            0 => 0,
+            s => 1 + s.len(),
+        };
+
+        1 + self.ip().len() + scope + 2 + self.port().len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        // Check buffer length
+        if buff.len() < self.len() {
+            return Err(Error::BufferLength)
+        }
+
+        let mut n = '['.write(buff)?;
+        n += self.ip().write(&mut buff[n..])?;
+
+        // Write scope id where specified
+        if self.scope_id() != 0 {
+            n += '%'.write(&mut buff[n..])?;
+            n += self.scope_id().write(&mut buff[n..])?;
+        }
+
+        n += "]:".write(&mut buff[n..])?;
+        n += self.port().write(&mut buff[n..])?;
+
+        Ok(n)
+    }
+}
+
+/// [EncodeStr] implementation for [SocketAddr], see [SocketAddrV4] and [SocketAddrV6]
+impl EncodeStr for SocketAddr {
+    fn len(&self) -> usize {
+        match self {
+            SocketAddr::V4(a) => a.len(),
+            SocketAddr::V6(a) => a.len(),
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        match self {
+            SocketAddr::V4(a) => a.write(buff),
+            SocketAddr::V6(a) => a.write(buff),
+        }
+    }
+}
+
+/// Fetch the IPv4 address for IPv4-mapped IPv6 addresses (`::ffff:0:0/96`)
+fn mapped(addr: &Ipv6Addr) -> Option<Ipv4Addr> {
+    match addr.segments() {
+        [0, 0, 0, 0, 0, 0xffff, _, _] => addr.to_ipv4_mapped(),
+        _ => None,
+    }
+}
+
+/// Find the first longest run of two or more zero segments, returning (start, length)
+fn zero_run(segments: &[u16; 8]) -> Option<(usize, usize)> {
+    let mut best: Option<(usize, usize)> = None;
+    let mut i = 0;
+
+    while i < segments.len() {
+        // Measure run of zeroes from i
+        let len = segments[i..].iter().take_while(|v| **v == 0).count();
+
+        if len >= 2 && best.map(|(_, l)| len > l).unwrap_or(true) {
+            best = Some((i, len));
+        }
+
+        i += len.max(1);
+    }
+
+    best
+}
+
+/// Encoded length of a hex segment without leading zeroes
+fn hex_len(v: u16) -> usize {
+    match v {
+        0..=0xf => 1,
+        0x10..=0xff => 2,
+        0x100..=0xfff => 3,
+        _ => 4,
+    }
+}
+
+/// Encoded length of ':' separated hex segments
+fn joined_len(segments: &[u16]) -> usize {
+    segments.iter().map(|v| hex_len(*v)).sum::<usize>() + segments.len().saturating_sub(1)
+}
+
+/// Write ':' separated hex segments without leading zeroes
+fn write_joined(segments: &[u16], buff: &mut [u8]) -> Result<usize, Error> {
+    let mut n = 0;
+
+    for (i, v) in segments.iter().enumerate() {
+        if i > 0 {
+            n += ':'.write(&mut buff[n..])?;
+        }
+
+        let l = hex_len(*v);
+        if buff.len() < n + l {
+            return Err(Error::BufferLength)
+        }
+
+        for j in 0..l {
+            buff[n + j] = HEX_MAP[((v >> ((l - j - 1) * 4)) & 0x0F) as usize];
+        }
+        n += l;
+    }
+
+    Ok(n)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use core::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+    use crate::EncodeStr;
+
+    #[test]
+    fn encode_ipv4() {
+        let tests = &[
+            Ipv4Addr::LOCALHOST,
+            Ipv4Addr::UNSPECIFIED,
+            Ipv4Addr::BROADCAST,
+            Ipv4Addr::new(192, 168, 0, 1),
+        ];
+
+        for v in tests {
+            let mut buff = [0u8; 64];
+
+            let s = alloc::format!("{}", v);
+            let e = v.write_str(&mut buff).unwrap();
+
+            assert_eq!(v.len(), s.len(), "length mismatch for value: {}", v);
+            assert_eq!(e, &s);
+        }
+    }
+
+    #[test]
+    fn encode_ipv6() {
+        let tests = &[
+            (Ipv6Addr::LOCALHOST, "::1"),
+            (Ipv6Addr::UNSPECIFIED, "::"),
+            (Ipv6Addr::new(0x2001, 0xdb8, 0x85a3, 0x1, 0x2, 0x8a2e, 0x370, 0x7334), "2001:db8:85a3:1:2:8a2e:370:7334"),
+            (Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), "2001:db8::1"),
+            (Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 0, 0, 0, 1), "2001:db8:0:1::1"),
+            (Ipv6Addr::new(0x2001, 0, 0, 1, 0, 0, 1, 1), "2001::1:0:0:1:1"),
+            (Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0), "fe80::"),
+            (Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0xc0a8, 0x0001), "::ffff:192.168.0.1"),
+        ];
+
+        for (v, s) in tests {
+            let mut buff = [0u8; 64];
+
+            let e = v.write_str(&mut buff).unwrap();
+
+            assert_eq!(v.len(), s.len(), "length mismatch for value: {}", v);
+            assert_eq!(e, *s);
+            assert_eq!(e, &alloc::format!("{}", v));
+        }
+    }
+
+    #[test]
+    fn encode_socket_addr() {
+        let tests: &[(SocketAddr, &str)] = &[
+            (SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 8080).into(), "192.168.0.1:8080"),
+            (SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0).into(), "0.0.0.0:0"),
+            (SocketAddrV6::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 443, 0, 0).into(), "[2001:db8::1]:443"),
+            (SocketAddrV6::new(Ipv6Addr::LOCALHOST, 65535, 0, 0).into(), "[::1]:65535"),
+            (SocketAddrV6::new(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1), 80, 0, 2).into(), "[fe80::1%2]:80"),
+        ];
+
+        for (v, s) in tests {
+            let mut buff = [0u8; 64];
+
+            let e = v.write_str(&mut buff).unwrap();
+
+            assert_eq!(v.len(), s.len(), "length mismatch for value: {}", v);
+            assert_eq!(e, *s);
+            assert_eq!(e, alloc::format!("{}", v));
+        }
+    }
+}
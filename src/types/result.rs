@@ -0,0 +1,50 @@
+//! [EncodeStr] implementation for [Result], see [Tagged](crate::helpers::Tagged)
+//! for encoding with `Ok(..)` / `Err(..)` wrapping
+
+use crate::{EncodeStr, Error};
+
+/// [EncodeStr] implementation for [Result], writing the bare inner value
+/// for either variant (`Ok(5)` -> `5`, `Err("fail")` -> `fail`)
+impl <T: EncodeStr, E: EncodeStr> EncodeStr for Result<T, E> {
+    fn len(&self) -> usize {
+        match self {
+            Ok(v) => v.len(),
+            Err(e) => e.len(),
+        }
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        match self {
+            Ok(v) => v.write(buff),
+            Err(e) => e.write(buff),
+        }
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        match self {
+            Ok(v) => v.validate(),
+            Err(e) => e.validate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EncodeStr;
+
+    #[test]
+    fn encode_result() {
+        let tests: &[(Result<u16, i32>, &str)] = &[
+            (Ok(5), "5"),
+            (Ok(1234), "1234"),
+            (Err(-12), "-12"),
+        ];
+
+        for (v, s) in tests {
+            let mut buff = [0u8; 32];
+
+            assert_eq!(v.len(), s.len());
+            assert_eq!(v.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! [EncodeStr] implementation for [Duration], see [DurationFmt]
+
+use core::time::Duration;
+
+use crate::{EncodeStr, Error, helpers::DurationFmt};
+
+/// [EncodeStr] implementation for [Duration] as seconds with millisecond precision (`1.500s`)
+impl EncodeStr for Duration {
+    fn len(&self) -> usize {
+        DurationFmt::secs(*self, 3).len()
+    }
+
+    fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+        DurationFmt::secs(*self, 3).write(buff)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use crate::EncodeStr;
+
+    #[test]
+    fn encode_duration() {
+        let tests = &[
+            (Duration::from_secs(1), "1.000s"),
+            (Duration::from_millis(20), "0.020s"),
+            (Duration::from_millis(61_500), "61.500s"),
+        ];
+
+        for (d, s) in tests {
+            assert_eq!(d.len(), s.len(), "invalid length for value: {}", s);
+
+            let mut buff = [0u8; 32];
+            assert_eq!(d.write_str(&mut buff), Ok(*s));
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! [EncodeStr] implementations for tuples of encodable types
+
+use crate::{EncodeStr, Error, WriteMode};
+
+/// Helper macro for implementing tuple string encoding, writing members in order
+macro_rules! impl_tuple_encode {
+    ($($t:ident: $i:tt),+) => {
+        impl <$($t: EncodeStr),+> EncodeStr for ($($t,)+) {
+            fn len(&self) -> usize {
+                0 $(+ self.$i.len())+
+            }
+
+            fn write(&self, buff: &mut [u8]) -> Result<usize, Error> {
+                let mut n = 0;
+
+                $(
+                    n += self.$i.write(&mut buff[n..])?;
+                )+
+
+                Ok(n)
+            }
+
+            fn validate(&self) -> Result<(), Error> {
+                $(
+                    self.$i.validate()?;
+                )+
+
+                Ok(())
+            }
+
+            fn write_with(&self, buff: &mut [u8], mode: WriteMode) -> Result<usize, Error> {
+                if mode == WriteMode::Strict || self.len() <= buff.len() {
+                    return self.write(buff);
+                }
+
+                // Truncate members in order until the buffer is full
+                let mut n = 0;
+
+                $(
+                    if !crate::write_part_truncated(&self.$i, buff, &mut n)? {
+                        return Ok(n)
+                    }
+                )+
+
+                Ok(n)
+            }
+
+            fn write_chunks(&self, scratch: &mut [u8], mut f: impl FnMut(&str) -> Result<(), Error>) -> Result<(), Error> {
+                $(
+                    self.$i.write_chunks(scratch, &mut f)?;
+                )+
+
+                Ok(())
+            }
+        }
+    };
+}
+
+/// [EncodeStr] implementation for the unit type / empty tuple, encoding to an empty string
+impl EncodeStr for () {
+    fn len(&self) -> usize {
+        0
+    }
+
+    fn write(&self, _buff: &mut [u8]) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+impl_tuple_encode!(A: 0);
+impl_tuple_encode!(A: 0, B: 1);
+impl_tuple_encode!(A: 0, B: 1, C: 2);
+impl_tuple_encode!(A: 0, B: 1, C: 2, D: 3);
+impl_tuple_encode!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_tuple_encode!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_tuple_encode!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_tuple_encode!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+
+#[cfg(test)]
+mod test {
+    use crate::{EncodeStr, Error};
+
+    #[test]
+    fn encode_tuple2() {
+        let v = ("abc", 123u16);
+
+        let mut buff = [0u8; 32];
+        let s = v.write_str(&mut buff).unwrap();
+
+        assert_eq!(s, "abc123");
+        assert_eq!(v.len(), s.len());
+    }
+
+    #[test]
+    fn encode_tuple4() {
+        let v = ("something", ' ', 15u8, "/100");
+
+        let mut buff = [0u8; 32];
+        let s = v.write_str(&mut buff).unwrap();
+
+        assert_eq!(s, "something 15/100");
+        assert_eq!(v.len(), s.len());
+    }
+
+    #[test]
+    fn encode_unit() {
+        let mut buff = [0u8; 32];
+
+        assert_eq!(().len(), 0);
+        assert_eq!(().write_str(&mut buff), Ok(""));
+        assert_eq!(().write_str(&mut []), Ok(""));
+
+        let n = crate::write!(buff, "abc", (), 12u8, (), '!').unwrap();
+        assert_eq!(&buff[..n], b"abc12!");
+    }
+
+    #[test]
+    fn encode_tuple_buffer_length() {
+        let v = ("abc", ' ', 12345u32);
+
+        let mut buff = [0u8; 6];
+        assert_eq!(v.write(&mut buff), Err(Error::BufferLength));
+    }
+
+    #[test]
+    fn tuple_validate() {
+        use crate::helpers::{Bytes, Join, PadRight, Wrap};
+
+        let mut buff = [0u8; 16];
+
+        // Invalid members fail prior to modifying the buffer
+        let v = ("abc", Bytes(&[0xff, 0xfe]), 12u8);
+        assert_eq!(v.validate(), Err(Error::InvalidUtf8));
+        assert_eq!(v.write_str(&mut buff), Err(Error::InvalidUtf8));
+        assert_eq!(buff, [0u8; 16]);
+
+        // Forwarded through composite helpers
+        assert_eq!(Wrap::new("[", v, "]").write_str(&mut buff), Err(Error::InvalidUtf8));
+        assert_eq!(PadRight::new(Bytes(&[0xff]), 4, ' ').write_str(&mut buff), Err(Error::InvalidUtf8));
+        assert_eq!(PadRight::with_fill("a", 4, Bytes(&[0xff])).write_str(&mut buff), Err(Error::InvalidUtf8));
+        assert_eq!(Join::new(&[Bytes(&b"ok"[..]), Bytes(&[0xff][..])], ",").write_str(&mut buff), Err(Error::InvalidUtf8));
+        assert_eq!(buff, [0u8; 16]);
+
+        let v = ("abc", Bytes(b"def"));
+        assert_eq!(v.write_str(&mut buff), Ok("abcdef"));
+    }
+}
@@ -0,0 +1,88 @@
+//! Integer encoding throughput benchmark, comparing [EncodeStr] for `u64`
+//! against the previous two-pass (count digits via `len()`, then write) encoder.
+//! 
+//! Ignored by default, run with:
+//! `cargo test --release --test bench_int -- --ignored --nocapture`
+
+use std::time::{Duration, Instant};
+
+use emstr::{EncodeStr, Error, const_len::uint_len};
+
+/// Number of values to encode per pass
+const COUNT: usize = 100_000;
+
+/// Number of passes over the values
+const PASSES: usize = 20;
+
+/// Previous two-pass encoder, computing the length before emitting digits
+fn write_two_pass(v: u64, buff: &mut [u8]) -> Result<usize, Error> {
+    let n = uint_len(v as u128);
+    let mut v = v;
+
+    if buff.len() < n {
+        return Err(Error::BufferLength);
+    }
+
+    for i in 0..n {
+        buff[n - i - 1] = b'0' + (v % 10) as u8;
+        v /= 10;
+    }
+
+    Ok(n)
+}
+
+/// Encode all values with the provided encoder, returning elapsed time and total bytes
+fn run(values: &[u64], f: impl Fn(u64, &mut [u8]) -> Result<usize, Error>) -> (Duration, usize) {
+    let mut buff = [0u8; 32];
+    let mut total = 0;
+
+    let start = Instant::now();
+    for _ in 0..PASSES {
+        for v in values {
+            total += f(std::hint::black_box(*v), &mut buff).unwrap();
+        }
+        std::hint::black_box(&buff);
+    }
+
+    (start.elapsed(), total)
+}
+
+#[test]
+#[ignore]
+fn bench_encode_u64() {
+    let values: Vec<u64> = (0..COUNT).map(|_| rand::random::<u64>() >> (rand::random::<u32>() % 64)).collect();
+
+    // Encoded outputs must match for every value
+    let (mut x, mut y, mut z) = ([0u8; 32], [0u8; 32], [0u8; 32]);
+    for v in &values {
+        let n = write_two_pass(*v, &mut x).unwrap();
+        assert_eq!(v.write(&mut y), Ok(n));
+        assert_eq!(unsafe { v.write_unchecked(&mut z) }, n);
+        assert_eq!(&x[..n], &y[..n]);
+        assert_eq!(&x[..n], &z[..n]);
+    }
+
+    let (before, a) = run(&values, write_two_pass);
+    let (after, b) = run(&values, |v, buff| v.write(buff));
+    let (unchecked, c) = run(&values, |v, buff| Ok(unsafe { v.write_unchecked(buff) }));
+
+    // Total encoded lengths must match across timed runs
+    assert_eq!(a, b);
+    assert_eq!(a, c);
+
+    let ns = |d: Duration| d.as_nanos() as f64 / (COUNT * PASSES) as f64;
+    println!("two pass: {:.2} ns/value", ns(before));
+    println!("single pass: {:.2} ns/value", ns(after));
+    println!("unchecked: {:.2} ns/value", ns(unchecked));
+}
+
+#[test]
+fn bench_encoders_match() {
+    let (mut a, mut b) = ([0u8; 32], [0u8; 32]);
+
+    for v in [0, 1, 9, 10, 12345, u32::MAX as u64, u64::MAX].into_iter().chain((0..1000).map(|_| rand::random::<u64>())) {
+        let n = write_two_pass(v, &mut a).unwrap();
+        assert_eq!(v.write(&mut b), Ok(n));
+        assert_eq!(&a[..n], &b[..n]);
+    }
+}
@@ -0,0 +1,16 @@
+//! Compile failure tests for macro diagnostics
+//! 
+//! Snapshots match the exact rustc output, which changes between compiler
+//! releases, so these only run where `EMSTR_UI_TESTS` is set (on the pinned
+//! toolchain in CI). Regenerate with `TRYBUILD=overwrite`.
+
+#[test]
+fn ui() {
+    if std::env::var_os("EMSTR_UI_TESTS").is_none() {
+        eprintln!("skipping ui tests, set EMSTR_UI_TESTS to run");
+        return;
+    }
+
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,8 @@
+fn encode<T>(buff: &mut [u8], value: T) -> Result<usize, emstr::Error> {
+    emstr::write!(buff[..], "value: ", value, 12u8)
+}
+
+fn main() {
+    let mut buff = [0u8; 32];
+    let _ = encode(&mut buff, 12u8);
+}